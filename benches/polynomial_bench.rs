@@ -0,0 +1,125 @@
+//! Benchmarks for the core polynomial operations and the sumcheck protocol.
+//!
+//! `compute_g_j` (driving `run_protocol`) re-derives each round's polynomial
+//! from scratch via `partial_eval` + `bool_sum`, which is `O(n^2 * 2^n)`
+//! overall. Comparing its `run_protocol` numbers against `bool_sum`'s
+//! should make that quadratic-in-`n` blowup visible, and motivate switching
+//! to `OptimalProver`'s `O(n * 2^n)` bookkeeping-table approach.
+//!
+//! `partial_eval`, `bool_sum`, `Mul`, and `run_protocol` are parameterized
+//! by `num_vars` (via `BenchmarkId::from_parameter`) so each one's growth
+//! curve is visible in the criterion report, not just a single data point.
+
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use sumcheck::{compute_g_j, eval_hypercube, run_protocol_silent, MultiVarPolynomial};
+#[cfg(feature = "parallel")]
+use sumcheck::compute_g_j_parallel;
+
+const MODULUS: i32 = 65521;
+
+fn random_poly(num_vars: usize, num_terms: usize, max_degree: usize) -> MultiVarPolynomial {
+    let mut rng = StdRng::seed_from_u64(42);
+    MultiVarPolynomial::random(num_vars, num_terms, max_degree, MODULUS, &mut rng)
+}
+
+fn bench_partial_eval(c: &mut Criterion) {
+    let mut group = c.benchmark_group("partial_eval");
+    for num_vars in [8, 12, 16] {
+        let poly = random_poly(num_vars, 1000, 3);
+        group.bench_with_input(BenchmarkId::from_parameter(num_vars), &poly, |b, poly| {
+            b.iter(|| black_box(poly).partial_eval(&[(0, 5)]).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn bench_bool_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bool_sum");
+    for num_vars in [8, 12, 16] {
+        let poly = random_poly(num_vars, 200, 3);
+        group.bench_with_input(BenchmarkId::from_parameter(num_vars), &poly, |b, poly| {
+            b.iter(|| black_box(poly).bool_sum());
+        });
+    }
+    group.finish();
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+    for num_vars in [8, 12, 16] {
+        let a = random_poly(num_vars, 50, 1);
+        let b = random_poly(num_vars, 50, 1);
+        group.bench_with_input(BenchmarkId::from_parameter(num_vars), &(a, b), |bencher, (a, b)| {
+            bencher.iter(|| black_box(a).clone() * black_box(b).clone());
+        });
+    }
+    group.finish();
+}
+
+fn bench_run_protocol(c: &mut Criterion) {
+    const RUN_PROTOCOL_MODULUS: i32 = 8009;
+    let mut group = c.benchmark_group("run_protocol");
+    for num_vars in [8, 10, 12] {
+        let mut rng = StdRng::seed_from_u64(42);
+        let poly = MultiVarPolynomial::random(num_vars, 100, 3, RUN_PROTOCOL_MODULUS, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(num_vars), &poly, |b, poly| {
+            b.iter(|| {
+                run_protocol_silent(black_box(poly.clone()), BTreeMap::new(), BTreeMap::new()).unwrap()
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_eval_hypercube(c: &mut Criterion) {
+    let poly = random_poly(20, 100, 3);
+    c.bench_function("eval_hypercube_20vars", |b| {
+        b.iter(|| eval_hypercube(black_box(&poly)).unwrap());
+    });
+}
+
+fn bench_random_generation(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    c.bench_function("random_generation_20vars_1000terms", |b| {
+        b.iter(|| MultiVarPolynomial::random(20, 1000, 3, MODULUS, &mut rng));
+    });
+}
+
+fn bench_compute_g_j(c: &mut Criterion) {
+    let poly = random_poly(16, 500, 3);
+    c.bench_function("compute_g_j_16vars", |b| {
+        b.iter(|| compute_g_j(black_box(&poly), 0, &[]).unwrap());
+    });
+}
+
+#[cfg(feature = "parallel")]
+fn bench_compute_g_j_parallel(c: &mut Criterion) {
+    let poly = random_poly(16, 500, 3);
+    c.bench_function("compute_g_j_parallel_16vars", |b| {
+        b.iter(|| compute_g_j_parallel(black_box(&poly), 0, &[]).unwrap());
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_partial_eval,
+    bench_bool_sum,
+    bench_mul,
+    bench_run_protocol,
+    bench_eval_hypercube,
+    bench_random_generation,
+    bench_compute_g_j,
+);
+
+#[cfg(feature = "parallel")]
+criterion_group!(parallel_benches, bench_compute_g_j_parallel);
+
+#[cfg(feature = "parallel")]
+criterion_main!(benches, parallel_benches);
+#[cfg(not(feature = "parallel"))]
+criterion_main!(benches);
@@ -0,0 +1,104 @@
+//! Interop with the arkworks ecosystem's sparse multivariate polynomial
+//! layout (`ark_poly::polynomial::multivariate::SparsePolynomial`), gated
+//! behind the `arkworks` feature since this crate has no dependency on
+//! `ark-ff`/`ark-poly` itself.
+//!
+//! arkworks represents a term as `SparseTerm`, a sorted list of
+//! `(variable, power)` pairs that omits variables with power zero, paired
+//! with a field-element coefficient. [`ArkworksTerm`] mirrors that layout
+//! directly (using `i32` in place of a generic field element, since this
+//! crate works over `Z/pZ` rather than an arbitrary field), so a caller
+//! linking against arkworks can map each pair's coefficient into their own
+//! field type at the call site.
+
+use alloc::vec::Vec;
+
+use crate::{MultiVarPolynomial, PolyError};
+
+/// One term in the arkworks `SparseTerm` layout: a coefficient paired with
+/// its `(variable, power)` pairs, sorted by variable index, omitting any
+/// variable with power zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArkworksTerm {
+    pub coeff: i32,
+    pub powers: Vec<(usize, usize)>,
+}
+
+/// Converts `poly` into arkworks' `(coefficient, SparseTerm)` layout: one
+/// [`ArkworksTerm`] per non-zero term, with zero-power variables omitted
+/// from `powers`.
+pub fn to_arkworks_terms(poly: &MultiVarPolynomial) -> Vec<ArkworksTerm> {
+    poly.terms
+        .iter()
+        .filter(|(_, &coeff)| coeff != 0)
+        .map(|(exponents, &coeff)| {
+            let powers = exponents
+                .iter()
+                .enumerate()
+                .filter(|&(_, &power)| power != 0)
+                .map(|(var, &power)| (var, power))
+                .collect();
+            ArkworksTerm { coeff, powers }
+        })
+        .collect()
+}
+
+/// Inverse of [`to_arkworks_terms`]: reconstructs a [`MultiVarPolynomial`]
+/// with `num_vars` variables over `modulus` from arkworks-layout terms.
+///
+/// Errors if a term references a variable index `>= num_vars`.
+pub fn from_arkworks_terms(
+    num_vars: usize,
+    modulus: i32,
+    terms: &[ArkworksTerm],
+) -> Result<MultiVarPolynomial, PolyError> {
+    let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+    for term in terms {
+        let mut exponents = alloc::vec![0usize; num_vars];
+        for &(var, power) in &term.powers {
+            if var >= num_vars {
+                return Err(PolyError::DimensionMismatch { expected: num_vars, found: var + 1 });
+            }
+            exponents[var] = power;
+        }
+        poly.add_term(exponents, term.coeff);
+    }
+    Ok(poly)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_round_trip_through_arkworks_terms() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![2, 0, 1], 5);
+        poly.add_term(vec![0, 1, 0], 7);
+        poly.add_term(vec![0, 0, 0], 3);
+
+        let terms = to_arkworks_terms(&poly);
+        let round_tripped = from_arkworks_terms(3, modulus, &terms).unwrap();
+        assert_eq!(round_tripped, poly);
+    }
+
+    #[test]
+    fn test_to_arkworks_terms_omits_zero_power_variables() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![2, 0, 1], 5);
+
+        let terms = to_arkworks_terms(&poly);
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].coeff, 5);
+        assert_eq!(terms[0].powers, vec![(0, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn test_from_arkworks_terms_rejects_out_of_range_variable() {
+        let terms = vec![ArkworksTerm { coeff: 1, powers: vec![(5, 1)] }];
+        assert!(from_arkworks_terms(2, 13, &terms).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! An async version of the sumcheck protocol that communicates over
+//! [`tokio::sync::mpsc`] channels instead of calling straight into a
+//! verifier function, so the prover and verifier can run as independent
+//! tasks (and, eventually, on opposite ends of a network connection).
+//!
+//! [`run_protocol_async`] plays the prover's role: it computes each round's
+//! `g_j` from `poly`, sends it down `prover_tx`, and awaits the matching
+//! challenge on `verifier_rx` before moving to the next round.
+//! [`verify_protocol_async`] plays the verifier's role against the other
+//! ends of the same two channels, running the same degree/consistency/final
+//! checks as [`crate::run_protocol_silent`].
+
+use rand::Rng;
+use tokio::sync::mpsc;
+
+use crate::{compute_g_j, random_field_element, MultiVarPolynomial, SumcheckError};
+
+/// A round message sent by the prover: the round polynomial `g_j`.
+pub type ProverMsg = MultiVarPolynomial;
+/// A round message sent by the verifier: a challenge `r_j`.
+pub type VerifierMsg = i32;
+
+/// Runs the prover's side of the protocol for `poly`, sending each round
+/// polynomial over `prover_tx` and awaiting the corresponding challenge on
+/// `verifier_rx`. Returns an error if either channel closes early or if a
+/// received challenge is out of range.
+pub async fn run_protocol_async(
+    poly: MultiVarPolynomial,
+    prover_tx: mpsc::Sender<ProverMsg>,
+    mut verifier_rx: mpsc::Receiver<VerifierMsg>,
+) -> Result<(), SumcheckError> {
+    let modulus = poly.modulus;
+    let mut challenges: Vec<i32> = Vec::with_capacity(poly.num_vars);
+
+    for j in 0..poly.num_vars {
+        let g_j = compute_g_j(&poly, j, &challenges)?;
+
+        prover_tx
+            .send(g_j)
+            .await
+            .map_err(|_| SumcheckError::UnsupportedOperation("prover channel closed".to_string()))?;
+
+        let r_j = verifier_rx
+            .recv()
+            .await
+            .ok_or_else(|| SumcheckError::UnsupportedOperation("verifier channel closed".to_string()))?;
+        if r_j < 0 || r_j >= modulus {
+            return Err(SumcheckError::InvalidChallenge { round: j, value: r_j });
+        }
+
+        challenges.push(r_j);
+    }
+
+    Ok(())
+}
+
+/// Runs the verifier's side of the protocol for `poly`, receiving each
+/// round polynomial over `prover_rx`, checking it, and sending back a
+/// challenge drawn via `rng` on `verifier_tx`. Returns `Ok(true)` if every
+/// round and the final oracle check pass, or an error describing the first
+/// failure otherwise.
+pub async fn verify_protocol_async(
+    poly: &MultiVarPolynomial,
+    mut prover_rx: mpsc::Receiver<ProverMsg>,
+    verifier_tx: mpsc::Sender<VerifierMsg>,
+    rng: &mut impl Rng,
+) -> Result<bool, SumcheckError> {
+    let modulus = poly.modulus;
+    let claimed_sum = poly.bool_sum();
+    let mut expected = claimed_sum;
+    let mut challenges: Vec<i32> = Vec::with_capacity(poly.num_vars);
+
+    for j in 0..poly.num_vars {
+        let g_j = prover_rx
+            .recv()
+            .await
+            .ok_or_else(|| SumcheckError::UnsupportedOperation("prover channel closed".to_string()))?;
+
+        let expected_degree = poly.expected_round_degree(j);
+        let actual_degree = g_j.degree_in_var(0);
+        if actual_degree > expected_degree {
+            return Err(SumcheckError::DegreeCheckFailed {
+                round: j,
+                expected: expected_degree,
+                found: actual_degree,
+            });
+        }
+
+        let g_j_at_0 = g_j.evaluate(&[0])?;
+        let g_j_at_1 = g_j.evaluate(&[1])?;
+        if (g_j_at_0 + g_j_at_1).rem_euclid(modulus) != expected {
+            return Err(SumcheckError::ConsistencyCheckFailed { round: j });
+        }
+
+        let r_j = random_field_element(modulus, rng);
+        verifier_tx
+            .send(r_j)
+            .await
+            .map_err(|_| SumcheckError::UnsupportedOperation("verifier channel closed".to_string()))?;
+
+        expected = g_j.evaluate(&[r_j])?;
+        challenges.push(r_j);
+    }
+
+    let final_eval = poly.evaluate(&challenges)?;
+    Ok(final_eval == expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn sample_poly(modulus: i32) -> MultiVarPolynomial {
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 0], 3);
+        poly.add_term(vec![0, 0, 1], 1);
+        poly.add_term(vec![1, 1, 0], 1);
+        poly
+    }
+
+    #[tokio::test]
+    async fn test_async_protocol_accepts_honest_prover() {
+        let modulus = 97;
+        let poly = sample_poly(modulus);
+        let verifier_poly = poly.clone();
+
+        let (prover_tx, prover_rx) = mpsc::channel(1);
+        let (verifier_tx, verifier_rx) = mpsc::channel(1);
+
+        let prover = tokio::spawn(run_protocol_async(poly, prover_tx, verifier_rx));
+        let verifier = tokio::spawn(async move {
+            let mut rng = StdRng::seed_from_u64(7);
+            verify_protocol_async(&verifier_poly, prover_rx, verifier_tx, &mut rng).await
+        });
+
+        let (prover_result, verifier_result) = tokio::join!(prover, verifier);
+        assert!(prover_result.unwrap().is_ok());
+        assert_eq!(verifier_result.unwrap(), Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_async_protocol_rejects_cheating_prover() {
+        let modulus = 97;
+        let poly = sample_poly(modulus);
+        let verifier_poly = poly.clone();
+
+        let (prover_tx, prover_rx) = mpsc::channel(1);
+        let (verifier_tx, verifier_rx) = mpsc::channel(1);
+
+        // A cheating prover sends the zero polynomial for every round
+        // instead of computing `g_j` honestly.
+        let cheating_prover = tokio::spawn(async move {
+            let mut verifier_rx = verifier_rx;
+            for _ in 0..poly.num_vars {
+                let lie = MultiVarPolynomial::new(1, modulus);
+                if prover_tx.send(lie).await.is_err() {
+                    return;
+                }
+                if verifier_rx.recv().await.is_none() {
+                    return;
+                }
+            }
+        });
+        let verifier = tokio::spawn(async move {
+            let mut rng = StdRng::seed_from_u64(7);
+            verify_protocol_async(&verifier_poly, prover_rx, verifier_tx, &mut rng).await
+        });
+
+        let (_, verifier_result) = tokio::join!(cheating_prover, verifier);
+        assert!(verifier_result.unwrap().is_err());
+    }
+}
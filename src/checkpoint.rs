@@ -0,0 +1,167 @@
+//! Checkpointing for [`OptimalProver`], so a long-running prover over a
+//! large polynomial can save its round-by-round progress and resume later
+//! instead of restarting from scratch after an interruption.
+
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use crate::OptimalProver;
+
+/// A snapshot of [`OptimalProver`]'s state partway through the protocol,
+/// together with the verifier challenges accumulated so far (which the
+/// prover itself doesn't track — see [`crate::compute_g_j`]'s
+/// caller-supplied `fixed_challenges` for the same convention).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolCheckpoint {
+    pub round: usize,
+    pub accumulated_challenges: Vec<i32>,
+    pub current_table: Vec<i32>,
+    pub modulus: i32,
+    pub num_vars: usize,
+}
+
+/// Snapshots `prover`'s current state, paired with the challenges the
+/// caller has accumulated up to this round.
+pub fn save_checkpoint(prover: &OptimalProver, accumulated_challenges: &[i32]) -> ProtocolCheckpoint {
+    ProtocolCheckpoint {
+        round: prover.round,
+        accumulated_challenges: accumulated_challenges.to_vec(),
+        current_table: prover.table.clone(),
+        modulus: prover.modulus,
+        num_vars: prover.num_vars,
+    }
+}
+
+/// Rebuilds an [`OptimalProver`] in the exact state [`save_checkpoint`]
+/// captured. Resuming `prover.fold` with the same subsequent challenges a
+/// from-scratch run would have used produces the same round polynomials.
+pub fn restore_checkpoint(cp: &ProtocolCheckpoint) -> OptimalProver {
+    OptimalProver {
+        table: cp.current_table.clone(),
+        num_vars: cp.num_vars,
+        round: cp.round,
+        modulus: cp.modulus,
+    }
+}
+
+fn write_u64(writer: &mut impl Write, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_i32(writer: &mut impl Write, value: i32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_i32_vec(writer: &mut impl Write, values: &[i32]) -> io::Result<()> {
+    write_u64(writer, values.len() as u64)?;
+    for &value in values {
+        write_i32(writer, value)?;
+    }
+    Ok(())
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_i32(reader: &mut impl Read) -> io::Result<i32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(i32::from_le_bytes(buf))
+}
+
+fn read_i32_vec(reader: &mut impl Read) -> io::Result<Vec<i32>> {
+    let len = read_u64(reader)? as usize;
+    let mut values = Vec::with_capacity(len);
+    for _ in 0..len {
+        values.push(read_i32(reader)?);
+    }
+    Ok(values)
+}
+
+/// Writes `cp` to `path` in a simple little-endian binary layout: `round`,
+/// `modulus`, `num_vars` as fixed-width integers, followed by
+/// `accumulated_challenges` and `current_table` as length-prefixed `i32`
+/// vectors.
+pub fn checkpoint_to_file(cp: &ProtocolCheckpoint, path: &Path) -> io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write_u64(&mut file, cp.round as u64)?;
+    write_i32(&mut file, cp.modulus)?;
+    write_u64(&mut file, cp.num_vars as u64)?;
+    write_i32_vec(&mut file, &cp.accumulated_challenges)?;
+    write_i32_vec(&mut file, &cp.current_table)?;
+    Ok(())
+}
+
+/// Inverse of [`checkpoint_to_file`]: reads a [`ProtocolCheckpoint`] back
+/// from its binary encoding.
+pub fn checkpoint_from_file(path: &Path) -> io::Result<ProtocolCheckpoint> {
+    let mut file = std::fs::File::open(path)?;
+    let round = read_u64(&mut file)? as usize;
+    let modulus = read_i32(&mut file)?;
+    let num_vars = read_u64(&mut file)? as usize;
+    let accumulated_challenges = read_i32_vec(&mut file)?;
+    let current_table = read_i32_vec(&mut file)?;
+    Ok(ProtocolCheckpoint {
+        round,
+        accumulated_challenges,
+        current_table,
+        modulus,
+        num_vars,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiVarPolynomial;
+
+    #[test]
+    fn test_resuming_from_a_checkpoint_matches_running_from_scratch() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 1, 0], 2);
+        poly.add_term(vec![0, 1, 1], 3);
+        poly.add_term(vec![1, 0, 0], 5);
+
+        let challenges = [4, 7, 2];
+
+        let mut from_scratch = OptimalProver::from_poly(&poly);
+        for &r in &challenges {
+            from_scratch.fold(r);
+        }
+
+        let mut resumed = OptimalProver::from_poly(&poly);
+        resumed.fold(challenges[0]);
+        let cp = save_checkpoint(&resumed, &challenges[..1]);
+        let mut restored = restore_checkpoint(&cp);
+        for &r in &challenges[1..] {
+            restored.fold(r);
+        }
+
+        assert_eq!(restored.table, from_scratch.table);
+        assert_eq!(restored.round, from_scratch.round);
+    }
+
+    #[test]
+    fn test_checkpoint_round_trips_through_a_file() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 5);
+
+        let mut prover = OptimalProver::from_poly(&poly);
+        prover.fold(6);
+        let cp = save_checkpoint(&prover, &[6]);
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("sumcheck_checkpoint_test_{}.bin", std::process::id()));
+        checkpoint_to_file(&cp, &path).unwrap();
+        let round_tripped = checkpoint_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(round_tripped, cp);
+    }
+}
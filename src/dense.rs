@@ -0,0 +1,149 @@
+//! A dense representation for low-variable, low-degree polynomials, where
+//! the `BTreeMap`-based [`MultiVarPolynomial`] pays for cache-unfriendly
+//! lookups in performance-critical inner loops.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{modular_pow, MultiVarPolynomial, PolyError};
+
+/// A polynomial stored as a flat coefficient vector, indexed by a
+/// mixed-radix encoding of exponent vectors: variable `i` contributes a
+/// digit in `[0, radices[i])`, with variable 0 as the least significant
+/// digit. `radices[i]` is one more than the maximum degree of variable `i`
+/// observed when converting from a [`MultiVarPolynomial`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DensePolynomial {
+    pub coeffs: Vec<i32>,
+    pub radices: Vec<usize>,
+    pub num_vars: usize,
+    pub modulus: i32,
+}
+
+fn index_of(radices: &[usize], exponents: &[usize]) -> usize {
+    let mut index = 0;
+    let mut stride = 1;
+    for (&e, &radix) in exponents.iter().zip(radices.iter()) {
+        index += e * stride;
+        stride *= radix;
+    }
+    index
+}
+
+fn exponents_of(radices: &[usize], mut index: usize) -> Vec<usize> {
+    radices
+        .iter()
+        .map(|&radix| {
+            let e = index % radix;
+            index /= radix;
+            e
+        })
+        .collect()
+}
+
+impl DensePolynomial {
+    /// Converts a sparse polynomial to dense form, sizing each variable's
+    /// radix from its observed maximum degree.
+    pub fn from_sparse(poly: &MultiVarPolynomial) -> Self {
+        let radices: Vec<usize> = poly.degree_summary().per_var.iter().map(|&d| d + 1).collect();
+        let size: usize = radices.iter().product::<usize>().max(1);
+        let mut coeffs = vec![0i32; size];
+        for (exponents, &coeff) in &poly.terms {
+            if coeff == 0 {
+                continue;
+            }
+            coeffs[index_of(&radices, exponents)] = coeff;
+        }
+        DensePolynomial {
+            coeffs,
+            radices,
+            num_vars: poly.num_vars,
+            modulus: poly.modulus,
+        }
+    }
+
+    /// Converts back to the sparse `BTreeMap`-based representation.
+    pub fn to_sparse(&self) -> MultiVarPolynomial {
+        let mut poly = MultiVarPolynomial::new(self.num_vars, self.modulus);
+        for (index, &coeff) in self.coeffs.iter().enumerate() {
+            if coeff == 0 {
+                continue;
+            }
+            poly.add_term(exponents_of(&self.radices, index), coeff);
+        }
+        poly
+    }
+
+    /// Evaluates the polynomial at `point`, which must have `num_vars` entries.
+    pub fn eval(&self, point: &[i32]) -> Result<i32, PolyError> {
+        if point.len() != self.num_vars {
+            return Err(PolyError::DimensionMismatch {
+                expected: self.num_vars,
+                found: point.len(),
+            });
+        }
+        let modulus = self.modulus as i64;
+        let mut sum = 0i64;
+        for (index, &coeff) in self.coeffs.iter().enumerate() {
+            if coeff == 0 {
+                continue;
+            }
+            let exponents = exponents_of(&self.radices, index);
+            let mut term_value = coeff as i64;
+            for (&var_value, &exp) in point.iter().zip(exponents.iter()) {
+                let power = modular_pow(var_value, exp as u32, self.modulus) as i64;
+                term_value = (term_value * power).rem_euclid(modulus);
+            }
+            sum = (sum + term_value).rem_euclid(modulus);
+        }
+        Ok(sum as i32)
+    }
+
+    /// Fixes a subset of variables to the given values, returning a new
+    /// `DensePolynomial` over the remaining variables (renumbered in their
+    /// original relative order). Implemented via the sparse representation,
+    /// since fixing a variable changes the mixed-radix shape.
+    pub fn partial_eval(&self, values: &[(usize, i32)]) -> Result<Self, PolyError> {
+        let reduced = self.to_sparse().partial_eval(values)?;
+        Ok(DensePolynomial::from_sparse(&reduced))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dense_and_sparse_eval_agree() {
+        let modulus = 13;
+        // 2*x0^2*x1 + 3*x1*x2^2 + x0 + 5
+        let mut sparse = MultiVarPolynomial::new(3, modulus);
+        sparse.add_term(vec![2, 1, 0], 2);
+        sparse.add_term(vec![0, 1, 2], 3);
+        sparse.add_term(vec![1, 0, 0], 1);
+        sparse.add_term(vec![0, 0, 0], 5);
+
+        let dense = DensePolynomial::from_sparse(&sparse);
+        assert_eq!(dense.to_sparse(), sparse);
+
+        for point in MultiVarPolynomial::hypercube_iter(3) {
+            assert_eq!(dense.eval(&point).unwrap(), sparse.evaluate(&point).unwrap());
+        }
+        let point = vec![3, 7, 2];
+        assert_eq!(dense.eval(&point).unwrap(), sparse.evaluate(&point).unwrap());
+    }
+
+    #[test]
+    fn test_dense_partial_eval_matches_sparse() {
+        let modulus = 13;
+        let mut sparse = MultiVarPolynomial::new(3, modulus);
+        sparse.add_term(vec![2, 1, 0], 2);
+        sparse.add_term(vec![0, 1, 2], 3);
+        sparse.add_term(vec![1, 0, 0], 1);
+
+        let dense = DensePolynomial::from_sparse(&sparse);
+        let dense_result = dense.partial_eval(&[(0, 4)]).unwrap();
+        let sparse_result = sparse.partial_eval(&[(0, 4)]).unwrap();
+        assert_eq!(dense_result.to_sparse(), sparse_result);
+    }
+}
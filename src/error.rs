@@ -1,4 +1,6 @@
-use std::fmt;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
 
 /// Errors arising from polynomial construction and evaluation.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -9,6 +11,51 @@ pub enum PolyError {
     ModulusMismatch { left: i32, right: i32 },
     /// The requested modulus is not a positive prime number.
     InvalidModulus(i32),
+    /// `num_vars` is too large for the boolean hypercube to be enumerated.
+    TooManyVariables { num_vars: usize },
+    /// A partial evaluation's `values` fixed the same variable index twice.
+    DuplicateVariableInEval(usize),
+    /// A textual or binary polynomial representation was malformed, with a
+    /// human-readable explanation.
+    ParseError(String),
+    /// A univariate coefficient vector had a non-zero coefficient above
+    /// its declared degree bound.
+    DegreeExceedsBound { bound: usize, found: usize },
+    /// A rational point's denominator has no multiplicative inverse mod
+    /// the polynomial's modulus (in particular, a denominator of zero).
+    ZeroDenominator,
+    /// An operation that combines a slice of polynomials (e.g.
+    /// [`crate::MultiVarPolynomial::sum`]) was given an empty slice, with
+    /// no shape to fall back on for a zero result.
+    EmptyInput,
+}
+
+/// Finds the smallest prime factor of `n` via trial division up to
+/// `sqrt(n)`, for a more actionable [`PolyError::InvalidModulus`] message
+/// than just "not prime". Returns `None` for `n < 2`, which fails
+/// primality trivially rather than by having a factor.
+fn smallest_prime_factor(n: i32) -> Option<i32> {
+    if n < 2 {
+        return None;
+    }
+    let mut i = 2;
+    while i * i <= n {
+        if n % i == 0 {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Describes why `value` isn't a valid modulus, naming its smallest prime
+/// factor when one exists (i.e. when `value` is composite rather than
+/// merely non-positive or less than 2).
+pub(crate) fn describe_invalid_modulus(value: i32) -> String {
+    match smallest_prime_factor(value) {
+        Some(factor) => format!("{value} is not prime; its smallest factor is {factor}"),
+        None => format!("{value} is not a positive prime modulus"),
+    }
 }
 
 impl fmt::Display for PolyError {
@@ -22,13 +69,27 @@ impl fmt::Display for PolyError {
                 write!(f, "modulus mismatch: {left} != {right}")
             }
             PolyError::InvalidModulus(value) => {
-                write!(f, "{value} is not a positive prime modulus")
+                write!(f, "{}", describe_invalid_modulus(*value))
+            }
+            PolyError::TooManyVariables { num_vars } => {
+                write!(f, "{num_vars} variables is too many to enumerate the boolean hypercube")
+            }
+            PolyError::DuplicateVariableInEval(var) => {
+                write!(f, "variable {var} was fixed more than once in the same partial evaluation")
+            }
+            PolyError::ParseError(reason) => write!(f, "failed to parse polynomial: {reason}"),
+            PolyError::DegreeExceedsBound { bound, found } => {
+                write!(f, "degree {found} exceeds the bound of {bound}")
+            }
+            PolyError::ZeroDenominator => {
+                write!(f, "denominator has no multiplicative inverse mod the modulus")
             }
+            PolyError::EmptyInput => write!(f, "expected at least one polynomial, found none"),
         }
     }
 }
 
-impl std::error::Error for PolyError {}
+impl core::error::Error for PolyError {}
 
 /// Errors that can cause the sumcheck protocol to reject a proof.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -43,6 +104,15 @@ pub enum SumcheckError {
     FinalCheckFailed,
     /// A supplied challenge fell outside `[0, modulus)`.
     InvalidChallenge { round: usize, value: i32 },
+    /// Attempted to divide a univariate polynomial by the zero polynomial.
+    DivisionByZero,
+    /// The operation isn't supported for the given input, with a
+    /// human-readable explanation (e.g. a characteristic-p edge case).
+    UnsupportedOperation(String),
+    /// An operation that requires a multiplicative inverse (division, GCD,
+    /// ...) was attempted on a polynomial built with
+    /// [`crate::MultiVarPolynomial::new_ring`] over a composite modulus.
+    NotAField,
 }
 
 impl fmt::Display for SumcheckError {
@@ -60,11 +130,14 @@ impl fmt::Display for SumcheckError {
             SumcheckError::InvalidChallenge { round, value } => {
                 write!(f, "round {round}: challenge {value} is out of range")
             }
+            SumcheckError::DivisionByZero => write!(f, "division by the zero polynomial"),
+            SumcheckError::UnsupportedOperation(reason) => write!(f, "unsupported operation: {reason}"),
+            SumcheckError::NotAField => write!(f, "operation requires a field, but the modulus is not prime"),
         }
     }
 }
 
-impl std::error::Error for SumcheckError {}
+impl core::error::Error for SumcheckError {}
 
 impl From<PolyError> for SumcheckError {
     fn from(e: PolyError) -> Self {
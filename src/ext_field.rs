@@ -0,0 +1,320 @@
+//! `GF(p^k)` extension field support: elements are polynomials over
+//! `Z/pZ` of degree `< k`, reduced modulo a configurable irreducible
+//! polynomial. [`ExtFieldPolynomial`] mirrors [`crate::MultiVarPolynomial`]'s
+//! `add_term`/`evaluate`/`partial_eval`/`bool_sum` surface, but over this
+//! field instead of `Z/pZ` directly.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::PolyError;
+
+/// An element of `GF(p^k)`, represented as its coefficients
+/// `a_0 + a_1 x + ... + a_{k-1} x^{k-1}` modulo `p`, reduced against
+/// `irreducible` (the coefficients of `x^0..x^{k-1}` such that
+/// `x^k = -irreducible(x)`, i.e. the *reduction* polynomial, not including
+/// its own implicit leading `x^k` term).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtFieldElement {
+    pub coeffs: Vec<i32>,
+    pub modulus: i32,
+    pub irreducible: Vec<i32>,
+}
+
+fn mul_raw(a: &[i32], b: &[i32], modulus: i32) -> Vec<i32> {
+    let mut product = vec![0i32; a.len() + b.len() - 1];
+    for (i, &ai) in a.iter().enumerate() {
+        for (j, &bj) in b.iter().enumerate() {
+            let term = (ai as i64 * bj as i64).rem_euclid(modulus as i64);
+            product[i + j] = ((product[i + j] as i64 + term).rem_euclid(modulus as i64)) as i32;
+        }
+    }
+    product
+}
+
+/// Reduces `coeffs` (possibly longer than `irreducible.len()`) modulo the
+/// extension field's irreducible polynomial, by repeatedly substituting the
+/// highest-degree term via `x^k = -irreducible(x)` (equivalently,
+/// `+irreducible(x)` since every coefficient is already negated through
+/// `rem_euclid`).
+fn reduce(coeffs: &mut Vec<i32>, modulus: i32, irreducible: &[i32]) {
+    let k = irreducible.len();
+    while coeffs.len() > k {
+        let top_degree = coeffs.len() - 1;
+        let top = coeffs.pop().unwrap();
+        if top != 0 {
+            let shift = top_degree - k;
+            for (i, &c) in irreducible.iter().enumerate() {
+                let pos = shift + i;
+                if pos >= coeffs.len() {
+                    coeffs.resize(pos + 1, 0);
+                }
+                coeffs[pos] = ((coeffs[pos] as i64 + top as i64 * c as i64).rem_euclid(modulus as i64)) as i32;
+            }
+        }
+    }
+    coeffs.resize(k, 0);
+}
+
+impl ExtFieldElement {
+    /// Creates an element from its low-to-high coefficients, zero-padding
+    /// or truncating to `irreducible.len()` and reducing each coefficient
+    /// mod `modulus`.
+    pub fn new(mut coeffs: Vec<i32>, modulus: i32, irreducible: Vec<i32>) -> Self {
+        coeffs.resize(irreducible.len(), 0);
+        for c in coeffs.iter_mut() {
+            *c = c.rem_euclid(modulus);
+        }
+        ExtFieldElement { coeffs, modulus, irreducible }
+    }
+
+    pub fn zero(modulus: i32, irreducible: Vec<i32>) -> Self {
+        let k = irreducible.len();
+        ExtFieldElement::new(vec![0; k], modulus, irreducible)
+    }
+
+    pub fn one(modulus: i32, irreducible: Vec<i32>) -> Self {
+        let k = irreducible.len();
+        let mut coeffs = vec![0; k];
+        coeffs[0] = 1;
+        ExtFieldElement::new(coeffs, modulus, irreducible)
+    }
+
+    fn assert_same_field(&self, other: &ExtFieldElement) {
+        assert_eq!(self.modulus, other.modulus, "modulus mismatch between extension field elements");
+        assert_eq!(self.irreducible, other.irreducible, "irreducible polynomial mismatch between extension field elements");
+    }
+}
+
+impl Add for ExtFieldElement {
+    type Output = ExtFieldElement;
+    fn add(self, rhs: ExtFieldElement) -> ExtFieldElement {
+        self.assert_same_field(&rhs);
+        let coeffs = self
+            .coeffs
+            .iter()
+            .zip(rhs.coeffs.iter())
+            .map(|(&a, &b)| (a + b).rem_euclid(self.modulus))
+            .collect();
+        ExtFieldElement { coeffs, modulus: self.modulus, irreducible: self.irreducible }
+    }
+}
+
+impl Sub for ExtFieldElement {
+    type Output = ExtFieldElement;
+    fn sub(self, rhs: ExtFieldElement) -> ExtFieldElement {
+        self.assert_same_field(&rhs);
+        let coeffs = self
+            .coeffs
+            .iter()
+            .zip(rhs.coeffs.iter())
+            .map(|(&a, &b)| (a - b).rem_euclid(self.modulus))
+            .collect();
+        ExtFieldElement { coeffs, modulus: self.modulus, irreducible: self.irreducible }
+    }
+}
+
+impl Neg for ExtFieldElement {
+    type Output = ExtFieldElement;
+    fn neg(self) -> ExtFieldElement {
+        let modulus = self.modulus;
+        let coeffs = self.coeffs.iter().map(|&a| (-a).rem_euclid(modulus)).collect();
+        ExtFieldElement { coeffs, modulus, irreducible: self.irreducible }
+    }
+}
+
+impl Mul for ExtFieldElement {
+    type Output = ExtFieldElement;
+    fn mul(self, rhs: ExtFieldElement) -> ExtFieldElement {
+        self.assert_same_field(&rhs);
+        let mut product = mul_raw(&self.coeffs, &rhs.coeffs, self.modulus);
+        reduce(&mut product, self.modulus, &self.irreducible);
+        ExtFieldElement { coeffs: product, modulus: self.modulus, irreducible: self.irreducible }
+    }
+}
+
+/// A sparse multivariate polynomial over `GF(p^k)`, analogous to
+/// [`crate::MultiVarPolynomial`] but with [`ExtFieldElement`] coefficients.
+#[derive(Debug, Clone)]
+pub struct ExtFieldPolynomial {
+    pub terms: BTreeMap<Vec<usize>, ExtFieldElement>,
+    pub num_vars: usize,
+    pub modulus: i32,
+    pub irreducible: Vec<i32>,
+}
+
+impl ExtFieldPolynomial {
+    pub fn new(num_vars: usize, modulus: i32, irreducible: Vec<i32>) -> Self {
+        ExtFieldPolynomial { terms: BTreeMap::new(), num_vars, modulus, irreducible }
+    }
+
+    fn zero_elem(&self) -> ExtFieldElement {
+        ExtFieldElement::zero(self.modulus, self.irreducible.clone())
+    }
+
+    /// Adds `coeff` to the term at `exponents`, accumulating into any
+    /// existing entry rather than overwriting it.
+    pub fn add_term(&mut self, exponents: Vec<usize>, coeff: ExtFieldElement) {
+        let zero = self.zero_elem();
+        let entry = self.terms.entry(exponents).or_insert(zero);
+        *entry = entry.clone() + coeff;
+    }
+
+    /// The maximum exponent of `var_index` among terms with a non-zero
+    /// coefficient.
+    pub fn degree_in_var(&self, var_index: usize) -> usize {
+        let zero = self.zero_elem();
+        self.terms
+            .iter()
+            .filter(|(_, coeff)| **coeff != zero)
+            .map(|(exponents, _)| exponents[var_index])
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn evaluate(&self, point: &[ExtFieldElement]) -> Result<ExtFieldElement, PolyError> {
+        if point.len() != self.num_vars {
+            return Err(PolyError::DimensionMismatch { expected: self.num_vars, found: point.len() });
+        }
+        let mut sum = self.zero_elem();
+        for (exponents, coeff) in &self.terms {
+            let mut term_value = coeff.clone();
+            for (var_value, &exp) in point.iter().zip(exponents.iter()) {
+                for _ in 0..exp {
+                    term_value = term_value * var_value.clone();
+                }
+            }
+            sum = sum + term_value;
+        }
+        Ok(sum)
+    }
+
+    /// Fixes the variables named in `values` to field elements, returning a
+    /// new polynomial over the remaining variables, renumbered in their
+    /// original relative order.
+    pub fn partial_eval(&self, values: &[(usize, ExtFieldElement)]) -> Result<Self, PolyError> {
+        let fixed: BTreeMap<usize, &ExtFieldElement> = values.iter().map(|(i, v)| (*i, v)).collect();
+        let remaining: Vec<usize> = (0..self.num_vars).filter(|i| !fixed.contains_key(i)).collect();
+        let mut result = ExtFieldPolynomial::new(remaining.len(), self.modulus, self.irreducible.clone());
+
+        for (exponents, coeff) in &self.terms {
+            let mut new_coeff = coeff.clone();
+            for (&var, &value) in &fixed {
+                for _ in 0..exponents[var] {
+                    new_coeff = new_coeff * value.clone();
+                }
+            }
+            let new_exponents: Vec<usize> = remaining.iter().map(|&var| exponents[var]).collect();
+            result.add_term(new_exponents, new_coeff);
+        }
+        Ok(result)
+    }
+
+    /// Sums the polynomial over the boolean hypercube `{0,1}^num_vars`,
+    /// using the field's `zero`/`one` elements as the boolean values.
+    pub fn bool_sum(&self) -> ExtFieldElement {
+        let zero = ExtFieldElement::zero(self.modulus, self.irreducible.clone());
+        let one = ExtFieldElement::one(self.modulus, self.irreducible.clone());
+        let mut sum = zero.clone();
+        for mask in 0..(1u64 << self.num_vars) {
+            let point: Vec<ExtFieldElement> = (0..self.num_vars)
+                .map(|i| if (mask >> i) & 1 == 1 { one.clone() } else { zero.clone() })
+                .collect();
+            sum = sum + self.evaluate(&point).expect("point has num_vars entries");
+        }
+        sum
+    }
+}
+
+/// Computes the round polynomial `g_j` for a sumcheck run over `poly`: the
+/// polynomial in `x_j` alone obtained by fixing `x_0..x_{j-1}` to
+/// `fixed_challenges` and summing over the boolean hypercube of the
+/// remaining variables. Mirrors [`crate::compute_g_j`], but over
+/// [`ExtFieldElement`] coefficients.
+pub fn compute_g_j(
+    poly: &ExtFieldPolynomial,
+    j: usize,
+    fixed_challenges: &[ExtFieldElement],
+) -> ExtFieldPolynomial {
+    let remaining_vars: Vec<usize> = (j + 1..poly.num_vars).collect();
+    let zero = ExtFieldElement::zero(poly.modulus, poly.irreducible.clone());
+    let one = ExtFieldElement::one(poly.modulus, poly.irreducible.clone());
+
+    let mut result = ExtFieldPolynomial::new(1, poly.modulus, poly.irreducible.clone());
+    for mask in 0..(1u64 << remaining_vars.len()) {
+        let mut values: Vec<(usize, ExtFieldElement)> = Vec::with_capacity(j + remaining_vars.len());
+        for (i, r) in fixed_challenges.iter().enumerate() {
+            values.push((i, r.clone()));
+        }
+        for (bit, &var) in remaining_vars.iter().enumerate() {
+            let bit_value = if (mask >> bit) & 1 == 1 { one.clone() } else { zero.clone() };
+            values.push((var, bit_value));
+        }
+        let reduced = poly.partial_eval(&values).expect("fixed values index within num_vars");
+        for (exponents, coeff) in &reduced.terms {
+            result.add_term(exponents.clone(), coeff.clone());
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // GF(2^4) via x^4 + x + 1, i.e. x^4 = x + 1 over GF(2).
+    const MODULUS: i32 = 2;
+    fn irreducible() -> Vec<i32> {
+        vec![1, 1, 0, 0]
+    }
+
+    fn elem(coeffs: Vec<i32>) -> ExtFieldElement {
+        ExtFieldElement::new(coeffs, MODULUS, irreducible())
+    }
+
+    #[test]
+    fn test_gf16_multiplication_matches_known_reduction() {
+        // x * x^3 = x^4 = x + 1 in this field.
+        let x = elem(vec![0, 1, 0, 0]);
+        let x3 = elem(vec![0, 0, 0, 1]);
+        assert_eq!(x * x3, elem(vec![1, 1, 0, 0]));
+    }
+
+    #[test]
+    fn test_gf16_one_is_multiplicative_identity() {
+        let a = elem(vec![1, 0, 1, 1]);
+        let one = ExtFieldElement::one(MODULUS, irreducible());
+        assert_eq!(a.clone() * one, a);
+    }
+
+    #[test]
+    fn test_sumcheck_over_gf16_accepts_honest_proof() {
+        // poly = x_0 * x_1 + x_1 over GF(2^4).
+        let mut poly = ExtFieldPolynomial::new(2, MODULUS, irreducible());
+        poly.add_term(vec![1, 1], ExtFieldElement::one(MODULUS, irreducible()));
+        poly.add_term(vec![0, 1], ExtFieldElement::one(MODULUS, irreducible()));
+
+        let claimed_sum = poly.bool_sum();
+        let mut challenges = Vec::new();
+        let mut expected = claimed_sum;
+
+        for j in 0..poly.num_vars {
+            let g_j = compute_g_j(&poly, j, &challenges);
+
+            let zero = ExtFieldElement::zero(MODULUS, irreducible());
+            let one = ExtFieldElement::one(MODULUS, irreducible());
+            let g_at_0 = g_j.evaluate(std::slice::from_ref(&zero)).unwrap();
+            let g_at_1 = g_j.evaluate(std::slice::from_ref(&one)).unwrap();
+            assert_eq!(g_at_0 + g_at_1, expected, "consistency check failed at round {j}");
+
+            let r_j = elem(vec![1, 1, 0, 0]); // an arbitrary fixed challenge
+            expected = g_j.evaluate(std::slice::from_ref(&r_j)).unwrap();
+            challenges.push(r_j);
+        }
+
+        let final_eval = poly.evaluate(&challenges).unwrap();
+        assert_eq!(final_eval, expected);
+    }
+}
@@ -0,0 +1,129 @@
+//! A field element newtype, so `i32` coefficients can be replaced with a
+//! type that always carries its modulus and stays reduced.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// An element of `Z/modulus Z`, always kept in `[0, modulus)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldElement {
+    value: i32,
+    modulus: i32,
+}
+
+impl FieldElement {
+    /// Creates a field element, reducing `value` into `[0, modulus)`.
+    pub fn new(value: i32, modulus: i32) -> Self {
+        FieldElement {
+            value: value.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn modulus(&self) -> i32 {
+        self.modulus
+    }
+
+    /// Raises `self` to the power `exp` via repeated squaring, using `i64`
+    /// intermediates so squaring near-modulus values never overflows.
+    pub fn pow(&self, exp: u32) -> Self {
+        let mut result: i64 = 1;
+        let mut base = self.value as i64;
+        let modulus = self.modulus as i64;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = (result * base).rem_euclid(modulus);
+            }
+            base = (base * base).rem_euclid(modulus);
+            exp >>= 1;
+        }
+        FieldElement {
+            value: result as i32,
+            modulus: self.modulus,
+        }
+    }
+
+    fn assert_same_field(&self, other: &FieldElement) {
+        assert_eq!(self.modulus, other.modulus, "modulus mismatch between field elements");
+    }
+}
+
+impl Add for FieldElement {
+    type Output = FieldElement;
+    fn add(self, rhs: FieldElement) -> FieldElement {
+        self.assert_same_field(&rhs);
+        let sum = (self.value as i64 + rhs.value as i64).rem_euclid(self.modulus as i64);
+        FieldElement { value: sum as i32, modulus: self.modulus }
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = FieldElement;
+    fn sub(self, rhs: FieldElement) -> FieldElement {
+        self.assert_same_field(&rhs);
+        let diff = (self.value as i64 - rhs.value as i64).rem_euclid(self.modulus as i64);
+        FieldElement { value: diff as i32, modulus: self.modulus }
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = FieldElement;
+    fn mul(self, rhs: FieldElement) -> FieldElement {
+        self.assert_same_field(&rhs);
+        let product = (self.value as i64 * rhs.value as i64).rem_euclid(self.modulus as i64);
+        FieldElement { value: product as i32, modulus: self.modulus }
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+    fn neg(self) -> FieldElement {
+        FieldElement {
+            value: (-(self.value as i64)).rem_euclid(self.modulus as i64) as i32,
+            modulus: self.modulus,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_associative_and_commutative() {
+        let modulus = 13;
+        let a = FieldElement::new(5, modulus);
+        let b = FieldElement::new(9, modulus);
+        let c = FieldElement::new(11, modulus);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + b, b + a);
+    }
+
+    #[test]
+    fn test_mul_distributive_over_add() {
+        let modulus = 13;
+        let a = FieldElement::new(5, modulus);
+        let b = FieldElement::new(9, modulus);
+        let c = FieldElement::new(11, modulus);
+        assert_eq!(a * (b + c), a * b + a * c);
+    }
+
+    #[test]
+    fn test_neg_and_sub() {
+        let modulus = 13;
+        let a = FieldElement::new(5, modulus);
+        let b = FieldElement::new(9, modulus);
+        assert_eq!(a - b, a + (-b));
+    }
+
+    #[test]
+    fn test_pow_overflow_safe_near_modulus() {
+        let modulus = i32::MAX / 2;
+        let a = FieldElement::new(modulus - 1, modulus);
+        assert_eq!(a.pow(2), a * a);
+    }
+}
@@ -1,32 +1,119 @@
 //! Core polynomial arithmetic and the sumcheck protocol.
+//!
+//! Built against `alloc` rather than `std` directly, so the `no_std`
+//! build (`--no-default-features`, enabling only `alloc`) keeps the
+//! polynomial and protocol types, including [`run_protocol_silent`] and
+//! [`run_protocol_with_callback`]. The `std` feature (on by default) adds
+//! back [`run_protocol`]'s printing, the JSON-based [`test_vector`]
+//! module, and the `sumcheck` binary.
 
-use std::collections::HashMap;
-use std::ops::{Add, Mul};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, MulAssign, SubAssign};
 
 use rand::Rng;
 
+#[cfg(feature = "arkworks")]
+pub mod arkworks_interop;
+#[cfg(feature = "async")]
+pub mod async_protocol;
+#[cfg(feature = "std")]
+pub mod checkpoint;
+pub mod dense;
 pub mod error;
+pub mod ext_field;
+pub mod field;
+pub mod ntt;
+#[cfg(feature = "std")]
+pub mod poly_io;
+#[cfg(feature = "std")]
+pub mod test_vector;
+pub mod transcript;
+#[cfg(feature = "std")]
+pub mod transcript_file;
+pub mod univariate;
+#[cfg(feature = "arkworks")]
+pub use arkworks_interop::{from_arkworks_terms, to_arkworks_terms, ArkworksTerm};
+#[cfg(feature = "async")]
+pub use async_protocol::{run_protocol_async, verify_protocol_async, ProverMsg, VerifierMsg};
+#[cfg(feature = "std")]
+pub use checkpoint::{checkpoint_from_file, checkpoint_to_file, restore_checkpoint, save_checkpoint, ProtocolCheckpoint};
+pub use dense::DensePolynomial;
 pub use error::{PolyError, SumcheckError};
+pub use ext_field::{ExtFieldElement, ExtFieldPolynomial};
+pub use field::FieldElement;
+pub use ntt::{is_primitive_root, ntt, ntt_mul, primitive_root};
+#[cfg(feature = "std")]
+pub use poly_io::{PolyReader, PolyWriter};
+#[cfg(feature = "std")]
+pub use test_vector::{generate_test_vector, run_test_vector, TestVector};
+pub use transcript::Transcript;
+#[cfg(feature = "std")]
+pub use transcript_file::{write_challenge_transcript, ChallengeSource};
+pub use univariate::{differentiate_univariate, is_monic, make_monic, poly_div_rem, poly_gcd, square_free_decomposition};
 
 /// A sparse multivariate polynomial over `Z/pZ`, represented as a map from
-/// exponent vectors to coefficients.
-#[derive(Debug, Clone, PartialEq)]
+/// exponent vectors to coefficients. `BTreeMap` gives deterministic,
+/// lexicographically-ordered iteration over terms.
+#[derive(Debug, Clone)]
 pub struct MultiVarPolynomial {
-    pub terms: HashMap<Vec<usize>, i32>,
+    pub terms: BTreeMap<Vec<usize>, i32>,
     pub num_vars: usize,
     pub modulus: i32,
+    /// Maximum exponent per variable among the currently non-zero terms,
+    /// maintained incrementally by `add_term` so `degree_in_var` is O(1) in
+    /// the common case instead of an O(T) scan. When a term cancels back to
+    /// zero and it held the cached max for one of its variables, `add_term`
+    /// falls back to an O(T) rescan for just that variable so the cache
+    /// never drifts into a high-water mark.
+    cached_degrees: Vec<usize>,
+}
+
+impl PartialEq for MultiVarPolynomial {
+    /// Two polynomials are equal iff they have the same shape and agree on
+    /// every non-zero term. A zero-coefficient entry in `terms` (e.g. left
+    /// over from an `add_term` that canceled to zero) must not affect
+    /// equality, so both sides are normalized before comparing.
+    fn eq(&self, other: &Self) -> bool {
+        if self.num_vars != other.num_vars || self.modulus != other.modulus {
+            return false;
+        }
+        let mut lhs = self.clone();
+        let mut rhs = other.clone();
+        lhs.normalize();
+        rhs.normalize();
+        lhs.terms == rhs.terms
+    }
+}
+
+/// The per-variable and total degree of a polynomial, as computed by
+/// [`MultiVarPolynomial::degree_summary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DegreeSummary {
+    pub per_var: Vec<usize>,
+    pub total: usize,
 }
 
 impl MultiVarPolynomial {
     /// Creates the zero polynomial in `num_vars` variables over `Z/modulus Z`.
     ///
-    /// Panics if `modulus` is not a positive prime number.
+    /// Panics if `modulus` is not a positive prime number, naming its
+    /// smallest prime factor when one exists (see
+    /// [`PolyError::InvalidModulus`]'s `Display` for the same message).
     pub fn new(num_vars: usize, modulus: i32) -> Self {
-        assert!(is_prime(modulus), "Modulus must be a positive prime number");
+        assert!(is_prime(modulus), "{}", error::describe_invalid_modulus(modulus));
         MultiVarPolynomial {
-            terms: HashMap::new(),
+            terms: BTreeMap::new(),
             num_vars,
             modulus,
+            cached_degrees: vec![0; num_vars],
         }
     }
 
@@ -39,8 +126,103 @@ impl MultiVarPolynomial {
             "exponent vector must have num_vars entries"
         );
         let reduced = coeff.rem_euclid(self.modulus);
-        let entry = self.terms.entry(exponents).or_insert(0);
+        let entry = self.terms.entry(exponents.clone()).or_insert(0);
         *entry = (*entry + reduced).rem_euclid(self.modulus);
+        if *entry != 0 {
+            for (var, &exp) in exponents.iter().enumerate() {
+                if exp > self.cached_degrees[var] {
+                    self.cached_degrees[var] = exp;
+                }
+            }
+        } else {
+            for (var, &exp) in exponents.iter().enumerate() {
+                if exp == self.cached_degrees[var] {
+                    self.cached_degrees[var] = self.max_exponent_in_var(var);
+                }
+            }
+        }
+    }
+
+    /// Recomputes the true maximum exponent of `var` among the currently
+    /// non-zero terms with an O(T) scan -- `add_term`'s fallback for when a
+    /// cancellation might have invalidated `cached_degrees[var]`.
+    fn max_exponent_in_var(&self, var: usize) -> usize {
+        self.terms
+            .iter()
+            .filter(|(_, &coeff)| coeff != 0)
+            .map(|(exponents, _)| exponents[var])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Creates the zero polynomial in `num_vars` variables over the ring
+    /// `Z/modulus Z`, skipping [`new`](Self::new)'s primality check.
+    ///
+    /// Addition, multiplication, and evaluation all still work over a ring
+    /// with a composite modulus, but operations that need a multiplicative
+    /// inverse — division, GCD, and anything built on [`mod_inverse`] —
+    /// error with [`SumcheckError::NotAField`] rather than silently
+    /// producing a wrong answer. Use [`is_field`](Self::is_field) to check
+    /// which regime a polynomial is in.
+    pub fn new_ring(num_vars: usize, modulus: i32) -> Self {
+        MultiVarPolynomial {
+            terms: BTreeMap::new(),
+            num_vars,
+            modulus,
+            cached_degrees: vec![0; num_vars],
+        }
+    }
+
+    /// Whether this polynomial's modulus is prime, i.e. whether `Z/modulus
+    /// Z` is a field rather than merely a ring. Always true for polynomials
+    /// built with [`new`](Self::new), which asserts primality; may be false
+    /// for those built with [`new_ring`](Self::new_ring).
+    pub fn is_field(&self) -> bool {
+        is_prime(self.modulus)
+    }
+
+    /// Returns the reduced coefficient of the monomial `x^exponents`, or 0
+    /// if it's absent or its coefficient has canceled to zero without a
+    /// [`normalize`](Self::normalize) call having removed the entry.
+    pub fn coefficient(&self, exponents: &[usize]) -> Result<i32, PolyError> {
+        if exponents.len() != self.num_vars {
+            return Err(PolyError::DimensionMismatch {
+                expected: self.num_vars,
+                found: exponents.len(),
+            });
+        }
+        Ok(self.terms.get(exponents).copied().unwrap_or(0))
+    }
+
+    /// Returns the constant term — the coefficient of the all-zero
+    /// exponent vector, or 0 if absent — equivalent to but cheaper than
+    /// `self.evaluate(&vec![0; self.num_vars])`, since it looks the term
+    /// up directly instead of walking every term in the polynomial.
+    pub fn constant_term(&self) -> i32 {
+        self.terms.get(&vec![0; self.num_vars]).copied().unwrap_or(0)
+    }
+
+    /// [`MultiVarPolynomial::coefficient`], wrapped as a [`FieldElement`]
+    /// for callers that want coefficients to always carry their modulus
+    /// alongside them instead of working with bare `i32`s. Terms are still
+    /// stored as plain `i32`s internally -- this is just an optional view
+    /// onto them.
+    pub fn coefficient_fe(&self, exponents: &[usize]) -> Result<FieldElement, PolyError> {
+        self.coefficient(exponents).map(|coeff| FieldElement::new(coeff, self.modulus))
+    }
+
+    /// [`MultiVarPolynomial::add_term`], taking the coefficient as a
+    /// [`FieldElement`] instead of a bare `i32`. Panics (via `add_term`'s
+    /// own modulus handling) if `coeff`'s modulus doesn't match `self`'s --
+    /// checked explicitly here so the mismatch is reported clearly instead
+    /// of silently re-reducing into the wrong field.
+    pub fn add_term_fe(&mut self, exponents: Vec<usize>, coeff: FieldElement) {
+        assert_eq!(
+            coeff.modulus(),
+            self.modulus,
+            "modulus mismatch between FieldElement and polynomial"
+        );
+        self.add_term(exponents, coeff.value());
     }
 
     /// Removes terms whose coefficient has reduced to zero.
@@ -48,7 +230,33 @@ impl MultiVarPolynomial {
         self.terms.retain(|_, coeff| *coeff != 0);
     }
 
-    /// Evaluates the polynomial at a fully-specified point.
+    /// Checks whether every coefficient is already in `[0, modulus)` with
+    /// no zero coefficients stored — the invariant [`add_term`](Self::add_term)
+    /// maintains, but one a `terms` map built or mutated directly (it's
+    /// `pub`) might not. Useful after deserialization or manual
+    /// construction, before trusting a polynomial in the protocol.
+    pub fn is_reduced(&self) -> bool {
+        self.terms
+            .values()
+            .all(|&coeff| coeff != 0 && (0..self.modulus).contains(&coeff))
+    }
+
+    /// Brings every coefficient into `[0, modulus)` and drops any that
+    /// reduce to zero, restoring the invariant [`is_reduced`](Self::is_reduced)
+    /// checks. Lets a caller sanitize a polynomial whose `terms` map was
+    /// built or mutated directly, rather than through [`add_term`](Self::add_term),
+    /// before using it in the protocol.
+    pub fn reduce(&mut self) {
+        for coeff in self.terms.values_mut() {
+            *coeff = coeff.rem_euclid(self.modulus);
+        }
+        self.normalize();
+    }
+
+    /// Evaluates the polynomial at a fully-specified point. Each coordinate
+    /// is reduced mod `modulus` internally (via `modular_pow`), so values
+    /// outside `[0, modulus)` — including negative ones — are accepted and
+    /// behave the same as their reduced form.
     pub fn evaluate(&self, point: &[i32]) -> Result<i32, PolyError> {
         if point.len() != self.num_vars {
             return Err(PolyError::DimensionMismatch {
@@ -61,24 +269,76 @@ impl MultiVarPolynomial {
             if coeff == 0 {
                 continue;
             }
-            let mut term_value = coeff;
+            let mut term_value = coeff as i64;
             for (var, &exp) in exponents.iter().enumerate() {
                 if exp > 0 {
-                    term_value =
-                        (term_value * modular_pow(point[var], exp as u32, self.modulus))
-                            .rem_euclid(self.modulus);
+                    term_value = (term_value * modular_pow(point[var], exp as u32, self.modulus) as i64)
+                        .rem_euclid(self.modulus as i64);
                 }
             }
-            sum = (sum + term_value).rem_euclid(self.modulus);
+            sum = ((sum as i64 + term_value).rem_euclid(self.modulus as i64)) as i32;
         }
         Ok(sum)
     }
 
+    /// Evaluates the polynomial at a point given as `(numerator,
+    /// denominator)` pairs, converting each fraction into the field element
+    /// `numerator * mod_inverse(denominator)` before delegating to
+    /// [`evaluate`](Self::evaluate) — for protocols whose challenge point is
+    /// naturally a rational number that must first be mapped into the field.
+    ///
+    /// Errors with [`PolyError::ZeroDenominator`] if any denominator has no
+    /// multiplicative inverse mod `self.modulus` (in particular, a
+    /// denominator of zero).
+    pub fn eval_fraction(&self, point: &[(i32, i32)]) -> Result<i32, PolyError> {
+        let mut field_point = Vec::with_capacity(point.len());
+        for &(num, den) in point {
+            let inv_den = mod_inverse(den, self.modulus).ok_or(PolyError::ZeroDenominator)?;
+            field_point.push(((num as i64 * inv_den as i64).rem_euclid(self.modulus as i64)) as i32);
+        }
+        self.evaluate(&field_point)
+    }
+
+    /// Evaluates this polynomial at `x` via Horner's method, for the
+    /// univariate case — the round polynomials the verifier handles in
+    /// [`run_protocol_silent`]. Unlike the generic [`evaluate`](Self::evaluate),
+    /// which calls `modular_pow` per term, this walks the dense coefficient
+    /// vector once from the highest degree down, doing one multiply-add
+    /// per degree instead of a full exponentiation per term.
+    ///
+    /// Errors unless `self.num_vars == 1`.
+    pub fn eval_univariate(&self, x: i32) -> Result<i32, PolyError> {
+        if self.num_vars != 1 {
+            return Err(PolyError::DimensionMismatch {
+                expected: 1,
+                found: self.num_vars,
+            });
+        }
+        let degree = self.terms.keys().map(|exponents| exponents[0]).max().unwrap_or(0);
+        let mut coeffs = vec![0i32; degree + 1];
+        for (exponents, &coeff) in &self.terms {
+            coeffs[exponents[0]] = coeff;
+        }
+        let x = x.rem_euclid(self.modulus) as i64;
+        let modulus = self.modulus as i64;
+        let mut result = 0i64;
+        for &coeff in coeffs.iter().rev() {
+            result = (result * x + coeff as i64).rem_euclid(modulus);
+        }
+        Ok(result as i32)
+    }
+
     /// Fixes the variables named in `values` to the given field elements,
+    /// which are reduced mod `modulus` internally just like in `evaluate`,
     /// returning the resulting polynomial over the remaining variables,
     /// renumbered `0..new_num_vars` in their original relative order.
     pub fn partial_eval(&self, values: &[(usize, i32)]) -> Result<Self, PolyError> {
-        let fixed: HashMap<usize, i32> = values.iter().copied().collect();
+        let mut fixed: BTreeMap<usize, i32> = BTreeMap::new();
+        for &(var, value) in values {
+            if fixed.insert(var, value).is_some() {
+                return Err(PolyError::DuplicateVariableInEval(var));
+            }
+        }
         let remaining: Vec<usize> = (0..self.num_vars).filter(|v| !fixed.contains_key(v)).collect();
 
         let mut result = MultiVarPolynomial::new(remaining.len(), self.modulus);
@@ -86,21 +346,71 @@ impl MultiVarPolynomial {
             if coeff == 0 {
                 continue;
             }
-            let mut new_coeff = coeff;
+            let mut new_coeff = coeff as i64;
             for (&var, &value) in &fixed {
                 let exp = exponents[var];
                 if exp > 0 {
-                    new_coeff =
-                        (new_coeff * modular_pow(value, exp as u32, self.modulus)).rem_euclid(self.modulus);
+                    new_coeff = (new_coeff * modular_pow(value, exp as u32, self.modulus) as i64)
+                        .rem_euclid(self.modulus as i64);
                 }
             }
             let new_exponents: Vec<usize> = remaining.iter().map(|&var| exponents[var]).collect();
-            result.add_term(new_exponents, new_coeff);
+            result.add_term(new_exponents, new_coeff as i32);
         }
         Ok(result)
     }
 
+    /// Clone-free counterpart to [`MultiVarPolynomial::partial_eval`] for
+    /// hot loops (such as `compute_g_j`'s per-round calls) that would
+    /// otherwise allocate a fresh result each time: clears `out` and
+    /// refills it in place with the same result `partial_eval` would have
+    /// returned, reusing `out.terms`'s existing map allocation.
+    pub fn partial_eval_into(&self, values: &[(usize, i32)], out: &mut MultiVarPolynomial) {
+        let fixed: BTreeMap<usize, i32> = values.iter().copied().collect();
+        let remaining: Vec<usize> = (0..self.num_vars).filter(|v| !fixed.contains_key(v)).collect();
+
+        out.terms.clear();
+        out.num_vars = remaining.len();
+        out.modulus = self.modulus;
+        out.cached_degrees.clear();
+        out.cached_degrees.resize(remaining.len(), 0);
+
+        for (exponents, &coeff) in &self.terms {
+            if coeff == 0 {
+                continue;
+            }
+            let mut new_coeff = coeff as i64;
+            for (&var, &value) in &fixed {
+                let exp = exponents[var];
+                if exp > 0 {
+                    new_coeff = (new_coeff * modular_pow(value, exp as u32, self.modulus) as i64)
+                        .rem_euclid(self.modulus as i64);
+                }
+            }
+            let new_exponents: Vec<usize> = remaining.iter().map(|&var| exponents[var]).collect();
+            out.add_term(new_exponents, new_coeff as i32);
+        }
+    }
+
+    /// Same as [`MultiVarPolynomial::partial_eval`], but also returns the
+    /// mapping from each new variable index to the original index it came
+    /// from: `mapping[i]` is the original index of new variable `i`. This
+    /// lets a caller later compose the reduced polynomial with another
+    /// polynomial that still refers to the original variable names.
+    pub fn partial_eval_tracked(&self, values: &[(usize, i32)]) -> Result<(Self, Vec<usize>), SumcheckError> {
+        let reduced = self.partial_eval(values)?;
+        let mapping: Vec<usize> = (0..self.num_vars)
+            .filter(|v| !values.iter().any(|&(fixed, _)| fixed == *v))
+            .collect();
+        Ok((reduced, mapping))
+    }
+
     /// Sums the polynomial over the entire boolean hypercube `{0,1}^num_vars`.
+    ///
+    /// The zero polynomial (no terms, as returned by
+    /// [`MultiVarPolynomial::new`] before any `add_term` call) has claimed
+    /// sum `0`: every round polynomial the honest prover sends for it is
+    /// also the zero polynomial, and the protocol accepts.
     pub fn bool_sum(&self) -> i32 {
         let mut sum = 0i32;
         for mask in 0..(1u64 << self.num_vars) {
@@ -112,14 +422,373 @@ impl MultiVarPolynomial {
         sum
     }
 
-    /// The maximum exponent of `var_index` among all non-zero terms.
+    /// Sums the polynomial over every boolean assignment to the variables
+    /// in `var_indices`, returning the polynomial in the remaining
+    /// variables, re-indexed in their original relative order (the same
+    /// convention [`MultiVarPolynomial::partial_eval`] uses). Generalizes
+    /// [`MultiVarPolynomial::bool_sum`] (which fixes every variable) to a
+    /// sub-cube over a chosen subset of them.
+    pub fn bool_sum_vars(&self, var_indices: &[usize]) -> Self {
+        let remaining = (0..self.num_vars).filter(|v| !var_indices.contains(v)).count();
+        let mut result = MultiVarPolynomial::new(remaining, self.modulus);
+        for mask in 0..(1u64 << var_indices.len()) {
+            let values: Vec<(usize, i32)> = var_indices
+                .iter()
+                .enumerate()
+                .map(|(i, &var)| (var, ((mask >> i) & 1) as i32))
+                .collect();
+            let assignment = self.partial_eval(&values).expect("var_indices are within range");
+            result += assignment;
+        }
+        result
+    }
+
+    /// The maximum exponent of `var_index` among all non-zero terms, read
+    /// from the cache maintained by `add_term`.
     pub fn degree_in_var(&self, var_index: usize) -> usize {
+        self.cached_degrees[var_index]
+    }
+
+    /// The per-round degree bound a verifier should check round polynomial
+    /// `g_j` against for variable `var_index` -- the single source of truth
+    /// for this, so every verifier call site agrees on it. For a plain
+    /// polynomial this is just [`MultiVarPolynomial::degree_in_var`], but
+    /// callers proving a product instance (e.g.
+    /// [`correlated_sumcheck_verify`]) sum this across the factors instead
+    /// of calling [`MultiVarPolynomial::degree_in_var`] directly, since the
+    /// product's degree bound in each variable is the sum of its factors'.
+    pub fn expected_round_degree(&self, var_index: usize) -> usize {
+        self.degree_in_var(var_index)
+    }
+
+    /// Computes the per-variable maximum exponents and the maximum total
+    /// degree in a single `O(T)` pass over `self.terms`, instead of calling
+    /// [`MultiVarPolynomial::degree_in_var`] once per variable.
+    pub fn degree_summary(&self) -> DegreeSummary {
+        let mut per_var = vec![0usize; self.num_vars];
+        let mut total = 0usize;
+        for (exponents, &coeff) in &self.terms {
+            if coeff == 0 {
+                continue;
+            }
+            let mut term_total = 0usize;
+            for (var, &exp) in exponents.iter().enumerate() {
+                per_var[var] = per_var[var].max(exp);
+                term_total += exp;
+            }
+            total = total.max(term_total);
+        }
+        DegreeSummary { per_var, total }
+    }
+
+    /// Equivalent to calling [`MultiVarPolynomial::degree_in_var`] for every
+    /// variable, but computed in a single `O(T)` pass via
+    /// [`MultiVarPolynomial::degree_summary`].
+    pub fn degree_sequence(&self) -> Vec<usize> {
+        self.degree_summary().per_var
+    }
+
+    /// Returns the sorted indices of variables that appear with a positive
+    /// exponent in at least one non-zero term, for callers that want to
+    /// skip variables a polynomial doesn't actually depend on (e.g. to
+    /// avoid summing over them in [`MultiVarPolynomial::bool_sum`]-style
+    /// optimizations).
+    pub fn active_vars(&self) -> Vec<usize> {
+        let mut active = vec![false; self.num_vars];
+        for (exponents, &coeff) in &self.terms {
+            if coeff == 0 {
+                continue;
+            }
+            for (var, &exp) in exponents.iter().enumerate() {
+                if exp > 0 {
+                    active[var] = true;
+                }
+            }
+        }
+        active
+            .into_iter()
+            .enumerate()
+            .filter_map(|(var, is_active)| is_active.then_some(var))
+            .collect()
+    }
+
+    /// Counts monomials with a non-zero coefficient. Unlike `self.terms.len()`,
+    /// this ignores entries [`MultiVarPolynomial::add_term`] has zeroed out
+    /// (it leaves the key in place rather than removing it), so it reflects
+    /// the polynomial's true sparsity.
+    pub fn num_terms(&self) -> usize {
+        self.terms.values().filter(|&&coeff| coeff != 0).count()
+    }
+
+    /// Approximates this polynomial's in-memory footprint: every stored
+    /// entry (including zeroed-out ones still held by `self.terms`, unlike
+    /// [`MultiVarPolynomial::num_terms`]) costs one exponent per variable
+    /// plus one coefficient.
+    pub fn estimated_bytes(&self) -> usize {
+        let bytes_per_term = self.num_vars * core::mem::size_of::<usize>() + core::mem::size_of::<i32>();
+        self.terms.len() * bytes_per_term
+    }
+
+    /// Returns `true` iff every exponent in every non-zero term is 0 or 1,
+    /// i.e. the polynomial could be the multilinear extension of some table.
+    pub fn is_multilinear(&self) -> bool {
         self.terms
             .iter()
             .filter(|(_, &coeff)| coeff != 0)
-            .map(|(exponents, _)| exponents[var_index])
-            .max()
-            .unwrap_or(0)
+            .all(|(exponents, _)| exponents.iter().all(|&e| e <= 1))
+    }
+
+    /// Compares `self` and `other` by value when both are effectively
+    /// constant (no non-zero term besides the all-zero exponent one),
+    /// ignoring `num_vars` -- a zero-variable constant and an otherwise
+    /// unused multi-variable polynomial holding the same constant compare
+    /// equal under this method even though they differ under [`PartialEq`].
+    /// Returns `false` if either polynomial isn't constant, or if their
+    /// moduli differ (there is no common modulus to compare against).
+    pub fn equals_as_value(&self, other: &Self) -> bool {
+        if self.modulus != other.modulus {
+            return false;
+        }
+        if !self.active_vars().is_empty() || !other.active_vars().is_empty() {
+            return false;
+        }
+        self.constant_term() == other.constant_term()
+    }
+
+    /// Formal partial derivative with respect to `var_index`: maps each term
+    /// `(e, c)` with `e[var_index] = k > 0` to `(e with that exponent
+    /// decremented, c*k mod modulus)`, dropping terms where the exponent was 0.
+    pub fn derivative(&self, var_index: usize) -> Self {
+        let mut result = MultiVarPolynomial::new(self.num_vars, self.modulus);
+        for (exponents, &coeff) in &self.terms {
+            let k = exponents[var_index];
+            if k == 0 {
+                continue;
+            }
+            let mut new_exponents = exponents.clone();
+            new_exponents[var_index] = k - 1;
+            let new_coeff = ((coeff as i64 * k as i64).rem_euclid(self.modulus as i64)) as i32;
+            result.add_term(new_exponents, new_coeff);
+        }
+        result
+    }
+
+    /// Reinterprets this polynomial's coefficients over `new_modulus`,
+    /// reducing each one mod `new_modulus` and dropping any that become
+    /// zero. Errors if `new_modulus` isn't a positive prime.
+    pub fn to_modulus(&self, new_modulus: i32) -> Result<Self, PolyError> {
+        if !is_prime(new_modulus) {
+            return Err(PolyError::InvalidModulus(new_modulus));
+        }
+        let mut result = MultiVarPolynomial::new(self.num_vars, new_modulus);
+        for (exponents, &coeff) in &self.terms {
+            let reduced = coeff.rem_euclid(new_modulus);
+            if reduced != 0 {
+                result.add_term(exponents.clone(), reduced);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Raises this polynomial to the power `exp`, via exponentiation by
+    /// squaring (`O(log exp)` multiplications instead of `O(exp)`).
+    /// `pow(0)` is the constant polynomial `1`, with the same `num_vars`
+    /// and `modulus` as `self`.
+    pub fn pow(&self, exp: usize) -> Self {
+        let mut result = MultiVarPolynomial::new(self.num_vars, self.modulus);
+        result.add_term(vec![0; self.num_vars], 1);
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base.clone();
+            }
+            base *= base.clone();
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Builds a univariate polynomial from `coeffs` (coefficient of `x^i` at
+    /// index `i`), rejecting it if any coefficient above `bound` is
+    /// non-zero. Useful for constructing a round polynomial that is
+    /// guaranteed to satisfy the verifier's degree check up front, rather
+    /// than discovering a violation only once [`run_protocol_silent`]
+    /// rejects it.
+    pub fn univariate_with_degree_bound(coeffs: &[i32], bound: usize, modulus: i32) -> Result<Self, PolyError> {
+        if let Some(degree) = coeffs.iter().enumerate().rposition(|(_, &coeff)| coeff != 0) {
+            if degree > bound {
+                return Err(PolyError::DegreeExceedsBound { bound, found: degree });
+            }
+        }
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        for (degree, &coeff) in coeffs.iter().enumerate() {
+            if coeff != 0 {
+                poly.add_term(vec![degree], coeff);
+            }
+        }
+        Ok(poly)
+    }
+
+    /// Substitutes variable `var_index` with the polynomial `replacement`,
+    /// expanding the result via repeated [`Mul`]/[`Add`]. `replacement` must
+    /// share this polynomial's `num_vars` and `modulus`.
+    pub fn substitute(&self, var_index: usize, replacement: &MultiVarPolynomial) -> Result<Self, PolyError> {
+        if replacement.num_vars != self.num_vars {
+            return Err(PolyError::DimensionMismatch {
+                expected: self.num_vars,
+                found: replacement.num_vars,
+            });
+        }
+        if replacement.modulus != self.modulus {
+            return Err(PolyError::ModulusMismatch {
+                left: self.modulus,
+                right: replacement.modulus,
+            });
+        }
+
+        let mut result = MultiVarPolynomial::new(self.num_vars, self.modulus);
+        for (exponents, &coeff) in &self.terms {
+            if coeff == 0 {
+                continue;
+            }
+            let mut rest_exponents = exponents.clone();
+            let power = rest_exponents[var_index];
+            rest_exponents[var_index] = 0;
+
+            let mut term_poly = MultiVarPolynomial::new(self.num_vars, self.modulus);
+            term_poly.add_term(rest_exponents, coeff);
+            for _ in 0..power {
+                term_poly *= replacement.clone();
+            }
+            result += term_poly;
+        }
+        Ok(result)
+    }
+
+    /// Composes this polynomial with a univariate polynomial `subst` by
+    /// replacing every occurrence of `x_{var_index}^k` with `subst(x_{var_index})^k`
+    /// (via [`MultiVarPolynomial::pow`]), while every other variable is left
+    /// untouched. Unlike [`MultiVarPolynomial::substitute`] (which replaces
+    /// `var_index` with a polynomial over the *same* `num_vars`, eliminating
+    /// it as a free variable), `subst` is expressed in its own single
+    /// variable and `var_index` remains free in the result, just at a
+    /// higher degree.
+    ///
+    /// Errors if `subst` isn't univariate, or shares a different modulus.
+    pub fn compose_var(&self, var_index: usize, subst: &MultiVarPolynomial) -> Result<Self, SumcheckError> {
+        if subst.num_vars != 1 {
+            return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+                expected: 1,
+                found: subst.num_vars,
+            }));
+        }
+        if subst.modulus != self.modulus {
+            return Err(SumcheckError::Poly(PolyError::ModulusMismatch {
+                left: self.modulus,
+                right: subst.modulus,
+            }));
+        }
+
+        let mut result = MultiVarPolynomial::new(self.num_vars, self.modulus);
+        for (exponents, &coeff) in &self.terms {
+            if coeff == 0 {
+                continue;
+            }
+            let mut rest_exponents = exponents.clone();
+            let power = rest_exponents[var_index];
+            rest_exponents[var_index] = 0;
+
+            let subst_pow = subst.pow(power);
+            let mut term_poly = MultiVarPolynomial::new(self.num_vars, self.modulus);
+            for (subst_exponents, &subst_coeff) in &subst_pow.terms {
+                if subst_coeff == 0 {
+                    continue;
+                }
+                let mut new_exponents = rest_exponents.clone();
+                new_exponents[var_index] = subst_exponents[0];
+                term_poly.add_term(new_exponents, subst_coeff);
+            }
+            term_poly *= coeff;
+            result += term_poly;
+        }
+        Ok(result)
+    }
+
+    /// Embeds this polynomial into a larger variable space, mapping old
+    /// variable `i` to new variable `var_map[i]` and zero-filling the rest.
+    pub fn with_num_vars(&self, new_num_vars: usize, var_map: &[usize]) -> Result<Self, PolyError> {
+        if var_map.len() != self.num_vars {
+            return Err(PolyError::DimensionMismatch {
+                expected: self.num_vars,
+                found: var_map.len(),
+            });
+        }
+        if var_map.iter().any(|&v| v >= new_num_vars) {
+            return Err(PolyError::DimensionMismatch {
+                expected: new_num_vars,
+                found: var_map.iter().copied().max().map_or(0, |m| m + 1),
+            });
+        }
+
+        let mut result = MultiVarPolynomial::new(new_num_vars, self.modulus);
+        for (exponents, &coeff) in &self.terms {
+            let mut new_exponents = vec![0usize; new_num_vars];
+            for (old_idx, &new_idx) in var_map.iter().enumerate() {
+                new_exponents[new_idx] = exponents[old_idx];
+            }
+            result.add_term(new_exponents, coeff);
+        }
+        Ok(result)
+    }
+
+    /// Returns an iterator over every point of `{0,1}^num_vars`, used by
+    /// [`hypercube_sum`] and other boolean-hypercube operations.
+    pub fn hypercube_iter(num_vars: usize) -> HypercubeIter {
+        HypercubeIter::new(num_vars)
+    }
+
+    /// Builds the multilinear extension of `f` directly from a closure
+    /// over boolean inputs, without hand-specifying its evaluation table:
+    /// calls `f` at every point of `{0,1}^num_vars` (as 0/1 bytes, in the
+    /// same binary-counting order as [`MultiVarPolynomial::hypercube_iter`])
+    /// and interpolates the results through [`from_hypercube_evals`].
+    pub fn mle_from_fn(num_vars: usize, modulus: i32, f: impl Fn(&[u8]) -> i32) -> Self {
+        let evals: Vec<i32> = (0..1u64 << num_vars)
+            .map(|mask| {
+                let bits: Vec<u8> = (0..num_vars).map(|i| ((mask >> i) & 1) as u8).collect();
+                f(&bits)
+            })
+            .collect();
+        from_hypercube_evals(&evals, modulus)
+    }
+
+    /// Builds the multilinear extension of a truth table given as a string
+    /// of `'0'`/`'1'` characters, e.g. `"01101001"` for 3 variables: `bits`
+    /// must have a power-of-two length, and position `i` becomes the
+    /// evaluation at the point [`MultiVarPolynomial::hypercube_iter`] visits
+    /// `i`-th, same as [`from_hypercube_evals`] expects.
+    ///
+    /// Errors if `bits` is empty, its length isn't a power of two, or it
+    /// contains a character other than `'0'`/`'1'`.
+    pub fn from_truth_table(bits: &str, modulus: i32) -> Result<Self, PolyError> {
+        if bits.is_empty() || !bits.len().is_power_of_two() {
+            return Err(PolyError::ParseError(format!(
+                "truth table length must be a non-zero power of two, got {}",
+                bits.len()
+            )));
+        }
+        let evals: Vec<i32> = bits
+            .chars()
+            .map(|c| match c {
+                '0' => Ok(0),
+                '1' => Ok(1),
+                other => Err(PolyError::ParseError(format!(
+                    "truth table must contain only '0'/'1', found '{other}'"
+                ))),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(from_hypercube_evals(&evals, modulus))
     }
 
     /// Builds a random polynomial with `num_terms` non-zero terms, each
@@ -141,6 +810,145 @@ impl MultiVarPolynomial {
         }
         poly
     }
+
+    /// Parses zero or more polynomials from `reader`, each record using the
+    /// same single-polynomial format as the `sumcheck` binary's input file:
+    ///
+    /// ```text
+    /// num_vars modulus
+    /// num_terms
+    /// exp_0 exp_1 ... exp_{num_vars-1} coeff
+    /// ...
+    /// ```
+    ///
+    /// Records are separated by one or more blank lines, letting batch
+    /// experiments load several polynomials from a single file or stream.
+    /// Requires the `std` feature, since it reads from a [`std::io::BufRead`].
+    #[cfg(feature = "std")]
+    pub fn many_from_reader(reader: impl std::io::BufRead) -> Result<Vec<Self>, PolyError> {
+        let mut polys = Vec::new();
+        let mut lines = reader.lines();
+        loop {
+            let header = loop {
+                match lines.next() {
+                    None => return Ok(polys),
+                    Some(line) => {
+                        let line = line.map_err(|e| PolyError::ParseError(e.to_string()))?;
+                        if !line.trim().is_empty() {
+                            break line;
+                        }
+                    }
+                }
+            };
+            let mut header_parts = header.split_whitespace();
+            let num_vars: usize = header_parts
+                .next()
+                .ok_or_else(|| PolyError::ParseError("missing num_vars".to_string()))?
+                .parse()
+                .map_err(|_| PolyError::ParseError("invalid num_vars".to_string()))?;
+            let modulus: i32 = header_parts
+                .next()
+                .ok_or_else(|| PolyError::ParseError("missing modulus".to_string()))?
+                .parse()
+                .map_err(|_| PolyError::ParseError("invalid modulus".to_string()))?;
+
+            let num_terms: usize = lines
+                .next()
+                .ok_or_else(|| PolyError::ParseError("missing term count line".to_string()))?
+                .map_err(|e| PolyError::ParseError(e.to_string()))?
+                .trim()
+                .parse()
+                .map_err(|_| PolyError::ParseError("invalid term count".to_string()))?;
+
+            let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+            for _ in 0..num_terms {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| PolyError::ParseError("missing term line".to_string()))?
+                    .map_err(|e| PolyError::ParseError(e.to_string()))?;
+                let parts: Vec<i32> = line
+                    .split_whitespace()
+                    .map(|s| s.parse().map_err(|_| PolyError::ParseError("invalid term entry".to_string())))
+                    .collect::<Result<Vec<i32>, PolyError>>()?;
+                if parts.len() != num_vars + 1 {
+                    return Err(PolyError::ParseError("term line has wrong number of entries".to_string()));
+                }
+                let (exponents, coeff) = parts.split_at(num_vars);
+                let exponents: Vec<usize> = exponents.iter().map(|&e| e as usize).collect();
+                poly.add_term(exponents, coeff[0]);
+            }
+            polys.push(poly);
+        }
+    }
+
+    /// Sums `polys` into a single polynomial in one pass, accumulating
+    /// every term into one result map instead of allocating an
+    /// intermediate polynomial per `+` the way `polys.iter().cloned().fold`
+    /// would. Errors if `polys` is empty (there's no shape to fall back on
+    /// for a zero result) or if `num_vars`/`modulus` aren't consistent
+    /// across all of them.
+    pub fn sum(polys: &[MultiVarPolynomial]) -> Result<Self, PolyError> {
+        let first = polys.first().ok_or(PolyError::EmptyInput)?;
+        let mut result = MultiVarPolynomial::new(first.num_vars, first.modulus);
+        for poly in polys {
+            if poly.num_vars != first.num_vars {
+                return Err(PolyError::DimensionMismatch {
+                    expected: first.num_vars,
+                    found: poly.num_vars,
+                });
+            }
+            if poly.modulus != first.modulus {
+                return Err(PolyError::ModulusMismatch {
+                    left: first.modulus,
+                    right: poly.modulus,
+                });
+            }
+            for (exponents, &coeff) in &poly.terms {
+                result.add_term(exponents.clone(), coeff);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Combines `polys` into `sum_i coeffs[i] * polys[i]` in one pass, for
+    /// batched sumcheck: several claims about distinct polynomials are
+    /// folded into one claim about this combination via random
+    /// coefficients, so a single sumcheck run over the result proves all of
+    /// them at once (with soundness loss bounded by the usual
+    /// Schwartz-Zippel argument over the choice of `coeffs`).
+    ///
+    /// Errors if `polys` is empty, if `coeffs.len() != polys.len()`, or if
+    /// `num_vars`/`modulus` aren't consistent across `polys`.
+    pub fn random_linear_combination(polys: &[MultiVarPolynomial], coeffs: &[i32]) -> Result<Self, PolyError> {
+        let first = polys.first().ok_or(PolyError::EmptyInput)?;
+        if coeffs.len() != polys.len() {
+            return Err(PolyError::DimensionMismatch {
+                expected: polys.len(),
+                found: coeffs.len(),
+            });
+        }
+
+        let mut result = MultiVarPolynomial::new(first.num_vars, first.modulus);
+        for (poly, &coeff) in polys.iter().zip(coeffs.iter()) {
+            if poly.num_vars != first.num_vars {
+                return Err(PolyError::DimensionMismatch {
+                    expected: first.num_vars,
+                    found: poly.num_vars,
+                });
+            }
+            if poly.modulus != first.modulus {
+                return Err(PolyError::ModulusMismatch {
+                    left: first.modulus,
+                    right: poly.modulus,
+                });
+            }
+            for (exponents, &term_coeff) in &poly.terms {
+                let scaled = ((coeff as i64 * term_coeff as i64).rem_euclid(first.modulus as i64)) as i32;
+                result.add_term(exponents.clone(), scaled);
+            }
+        }
+        Ok(result)
+    }
 }
 
 impl Add for MultiVarPolynomial {
@@ -170,23 +978,337 @@ impl Mul for MultiVarPolynomial {
                     .zip(right_exp.iter())
                     .map(|(a, b)| a + b)
                     .collect();
-                result.add_term(exponents, left_coeff * right_coeff);
+                let coeff = ((left_coeff as i64 * right_coeff as i64).rem_euclid(self.modulus as i64)) as i32;
+                result.add_term(exponents, coeff);
             }
         }
         result
     }
 }
 
-/// Trial-division primality test, sufficient for the field sizes this crate
-/// targets.
-pub fn is_prime(n: i32) -> bool {
-    if n < 2 {
-        return false;
-    }
-    if n <= 3 {
-        return true;
+impl AddAssign for MultiVarPolynomial {
+    /// Adds `rhs`'s terms into `self` directly via `add_term`, avoiding the
+    /// extra clone that `*self = self.clone() + rhs` would require.
+    fn add_assign(&mut self, rhs: MultiVarPolynomial) {
+        assert_eq!(self.num_vars, rhs.num_vars, "num_vars mismatch in +=");
+        assert_eq!(self.modulus, rhs.modulus, "modulus mismatch in +=");
+        for (exponents, coeff) in rhs.terms {
+            self.add_term(exponents, coeff);
+        }
     }
-    if n % 2 == 0 {
+}
+
+impl MulAssign<i32> for MultiVarPolynomial {
+    /// Multiplies every coefficient by `scalar` in place. A zero `scalar`
+    /// cancels every term to zero, so `cached_degrees` is reset to match
+    /// rather than left holding a stale high-water mark.
+    fn mul_assign(&mut self, scalar: i32) {
+        let scalar = scalar.rem_euclid(self.modulus);
+        for coeff in self.terms.values_mut() {
+            *coeff = (*coeff * scalar).rem_euclid(self.modulus);
+        }
+        if scalar == 0 {
+            self.cached_degrees.fill(0);
+        }
+    }
+}
+
+impl SubAssign for MultiVarPolynomial {
+    /// Subtracts `rhs`'s terms from `self` directly via `add_term` with
+    /// negated coefficients, avoiding the extra clone that
+    /// `*self = self.clone() - rhs` would require.
+    fn sub_assign(&mut self, rhs: MultiVarPolynomial) {
+        assert_eq!(self.num_vars, rhs.num_vars, "num_vars mismatch in -=");
+        assert_eq!(self.modulus, rhs.modulus, "modulus mismatch in -=");
+        for (exponents, coeff) in rhs.terms {
+            self.add_term(exponents, -coeff);
+        }
+    }
+}
+
+impl MulAssign for MultiVarPolynomial {
+    /// Multiplies `self` by `rhs` in place. Unlike `+=`/`-=`, every pair of
+    /// terms' exponents must be combined into a new term, so this cannot
+    /// avoid an intermediate allocation and reduces to cloning `self`.
+    fn mul_assign(&mut self, rhs: MultiVarPolynomial) {
+        assert_eq!(self.num_vars, rhs.num_vars, "num_vars mismatch in *=");
+        assert_eq!(self.modulus, rhs.modulus, "modulus mismatch in *=");
+        *self = self.clone() * rhs;
+    }
+}
+
+/// Iterates over every point of the boolean hypercube `{0,1}^num_vars`, in
+/// binary counting order (bit 0 of the counter is the first coordinate).
+pub struct HypercubeIter {
+    num_vars: usize,
+    current: u64,
+    total: u64,
+}
+
+impl HypercubeIter {
+    pub fn new(num_vars: usize) -> Self {
+        HypercubeIter {
+            num_vars,
+            current: 0,
+            total: 1u64 << num_vars,
+        }
+    }
+}
+
+impl Iterator for HypercubeIter {
+    type Item = Vec<i32>;
+
+    fn next(&mut self) -> Option<Vec<i32>> {
+        if self.current >= self.total {
+            return None;
+        }
+        let point = (0..self.num_vars)
+            .map(|i| ((self.current >> i) & 1) as i32)
+            .collect();
+        self.current += 1;
+        Some(point)
+    }
+}
+
+/// Builds the multilinear equality polynomial
+/// `eq(x, r) = prod_i (x_i r_i + (1 - x_i)(1 - r_i))` over `r.len()`
+/// variables, which is 1 when `x == r` (for boolean `r`) and 0 at every
+/// other boolean point.
+pub fn eq_polynomial(r: &[i32], modulus: i32) -> MultiVarPolynomial {
+    let n = r.len();
+    let mut result = MultiVarPolynomial::new(n, modulus);
+    result.add_term(vec![0; n], 1);
+    for (i, &r_i) in r.iter().enumerate() {
+        let a = (2 * r_i - 1).rem_euclid(modulus);
+        let b = (1 - r_i).rem_euclid(modulus);
+        let mut factor = MultiVarPolynomial::new(n, modulus);
+        let mut linear_exp = vec![0; n];
+        linear_exp[i] = 1;
+        factor.add_term(linear_exp, a);
+        factor.add_term(vec![0; n], b);
+        result *= factor;
+    }
+    result
+}
+
+/// Evaluates a univariate polynomial at each point of `domain`, producing a
+/// Reed-Solomon codeword. `domain` must contain distinct elements of
+/// `F_p`; for a degree-`d` polynomial and `domain.len() > d` this is a
+/// proximity-testable encoding, the basis of FRI and related polynomial
+/// commitment schemes.
+pub fn reed_solomon_encode(poly: &MultiVarPolynomial, domain: &[i32]) -> Result<Vec<i32>, SumcheckError> {
+    if poly.num_vars != 1 {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: 1,
+            found: poly.num_vars,
+        }));
+    }
+    domain
+        .iter()
+        .map(|&x| poly.evaluate(&[x]).map_err(SumcheckError::Poly))
+        .collect()
+}
+
+/// Evaluates a univariate polynomial at every element of the multiplicative
+/// subgroup generated by `generator`: `generator^0, generator^1, ...,
+/// generator^(subgroup_size - 1)`.
+///
+/// When `subgroup_size` is a power of two and exceeds `poly`'s degree, this
+/// runs in `O(subgroup_size * log(subgroup_size))` via [`ntt`], treating
+/// `generator` as the root of unity of order `subgroup_size`; otherwise it
+/// falls back to [`reed_solomon_encode`]'s naive `O(subgroup_size *
+/// degree)` approach of evaluating at each power of `generator` directly.
+/// Both paths compute the same values, just at different costs.
+///
+/// Errors unless `poly.num_vars == 1`, or if `generator^subgroup_size !=
+/// 1 (mod modulus)`.
+pub fn eval_over_subgroup(
+    poly: &MultiVarPolynomial,
+    generator: i32,
+    subgroup_size: usize,
+    modulus: i32,
+) -> Result<Vec<i32>, SumcheckError> {
+    if poly.num_vars != 1 {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: 1,
+            found: poly.num_vars,
+        }));
+    }
+    if subgroup_size == 0 || modular_pow(generator, subgroup_size as u32, modulus) != 1 {
+        return Err(SumcheckError::UnsupportedOperation(
+            "eval_over_subgroup requires generator^subgroup_size == 1 (mod modulus)".to_string(),
+        ));
+    }
+
+    let degree = poly.terms.keys().map(|exponents| exponents[0]).max().unwrap_or(0);
+    if subgroup_size.is_power_of_two() && subgroup_size > degree {
+        let mut coeffs = vec![0i32; subgroup_size];
+        for (exponents, &coeff) in &poly.terms {
+            coeffs[exponents[0]] = coeff;
+        }
+        ntt(&mut coeffs, modulus, generator, false);
+        return Ok(coeffs);
+    }
+
+    let domain: Vec<i32> = (0..subgroup_size as u32).map(|i| modular_pow(generator, i, modulus)).collect();
+    reed_solomon_encode(poly, &domain)
+}
+
+/// Recovers the univariate polynomial `f` of degree `< evals.len()` from
+/// its evaluations `evals[i] = f(generator^i)` over the multiplicative
+/// subgroup generated by `generator`, via the inverse NTT ([`ntt`] with
+/// `invert = true`). The inverse of [`eval_over_subgroup`]'s NTT fast path.
+///
+/// Errors if `evals.len()` isn't a power of two, or if
+/// `generator^evals.len() != 1 (mod modulus)`.
+pub fn interpolate_from_subgroup_evals(
+    evals: &[i32],
+    generator: i32,
+    modulus: i32,
+) -> Result<MultiVarPolynomial, SumcheckError> {
+    if evals.is_empty() || !evals.len().is_power_of_two() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "interpolate_from_subgroup_evals requires a non-empty, power-of-two number of evaluations"
+                .to_string(),
+        ));
+    }
+    if modular_pow(generator, evals.len() as u32, modulus) != 1 {
+        return Err(SumcheckError::UnsupportedOperation(
+            "interpolate_from_subgroup_evals requires generator^evals.len() == 1 (mod modulus)".to_string(),
+        ));
+    }
+
+    let mut coeffs = evals.to_vec();
+    ntt(&mut coeffs, modulus, generator, true);
+
+    let mut poly = MultiVarPolynomial::new(1, modulus);
+    for (exp, coeff) in coeffs.into_iter().enumerate() {
+        if coeff != 0 {
+            poly.add_term(vec![exp], coeff);
+        }
+    }
+    Ok(poly)
+}
+
+/// Independently verifies the claimed sum `C` that the prover computes at
+/// the start of [`run_protocol`], by iterating over all `2^num_vars`
+/// boolean inputs and evaluating `poly` at each. Use this as the reference
+/// implementation when testing a prover's claimed sum.
+///
+/// Errors if `poly.num_vars > 25`, since `2^26` evaluations is already
+/// impractical for this naive enumeration.
+pub fn hypercube_sum(poly: &MultiVarPolynomial) -> Result<i32, PolyError> {
+    if poly.num_vars > 25 {
+        return Err(PolyError::TooManyVariables { num_vars: poly.num_vars });
+    }
+    Ok(poly.bool_sum())
+}
+
+/// Computes `∑_{x ∈ {0,1}^n} f(x) * g(x) mod p`, the inner product of `f`
+/// and `g` over the boolean hypercube — a key operation in inner product
+/// arguments (Bulletproofs-style), correlation checks, and sumcheck for
+/// bilinear forms. `f` and `g` must share `num_vars` and `modulus`.
+///
+/// Builds the product polynomial `f * g` and delegates to
+/// [`hypercube_sum`], rather than evaluating both polynomials separately at
+/// every boolean point and accumulating the products.
+pub fn inner_product_hypercube(f: &MultiVarPolynomial, g: &MultiVarPolynomial) -> Result<i32, SumcheckError> {
+    if f.num_vars != g.num_vars {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: f.num_vars,
+            found: g.num_vars,
+        }));
+    }
+    if f.modulus != g.modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch {
+            left: f.modulus,
+            right: g.modulus,
+        }));
+    }
+    let product = f.clone() * g.clone();
+    Ok(hypercube_sum(&product)?)
+}
+
+/// Probabilistically tests whether `f` and `g` are the same polynomial using
+/// the Schwartz-Zippel lemma: picks a uniformly random point `x in F^n` and
+/// compares `f(x)` to `g(x)`. If `f != g`, the false-positive probability is
+/// at most `total_degree(f - g) / modulus`.
+///
+/// Returns `false` if `f` and `g` have different `num_vars` or `modulus`,
+/// since they cannot be compared at a common point.
+pub fn poly_eq_probabilistic(
+    f: &MultiVarPolynomial,
+    g: &MultiVarPolynomial,
+    rng: &mut impl Rng,
+) -> bool {
+    if f.num_vars != g.num_vars || f.modulus != g.modulus {
+        return false;
+    }
+    let point: Vec<i32> = (0..f.num_vars).map(|_| random_field_element(f.modulus, rng)).collect();
+    match (f.evaluate(&point), g.evaluate(&point)) {
+        (Ok(fv), Ok(gv)) => fv == gv,
+        _ => false,
+    }
+}
+
+/// Evaluates `poly` at a uniformly random point in `F^poly.num_vars`, as in
+/// [`poly_eq_probabilistic`], and returns the value as a Schwartz-Zippel
+/// fingerprint. Two equal polynomials evaluated at the *same* point always
+/// produce the same fingerprint; comparing fingerprints computed from
+/// independently-drawn points proves nothing, so use
+/// [`poly_fingerprints_match`] to compare two polynomials directly.
+pub fn poly_fingerprint(poly: &MultiVarPolynomial, rng: &mut impl Rng) -> i32 {
+    let point: Vec<i32> = (0..poly.num_vars).map(|_| random_field_element(poly.modulus, rng)).collect();
+    poly.evaluate(&point).expect("point has num_vars entries")
+}
+
+/// Checks whether `f` and `g` fingerprint to the same value at a single
+/// shared random point, under the "fingerprint" framing of
+/// [`poly_eq_probabilistic`] (which this delegates to). If `f != g`, the
+/// false-positive probability is at most `total_degree(f - g) / modulus`,
+/// by the Schwartz-Zippel lemma; it never returns `false` for `f == g`.
+pub fn poly_fingerprints_match(f: &MultiVarPolynomial, g: &MultiVarPolynomial, rng: &mut impl Rng) -> bool {
+    poly_eq_probabilistic(f, g, rng)
+}
+
+/// Applies `f` to every coefficient of `poly`, reducing each result mod
+/// `poly.modulus`, and returns the resulting polynomial. Lets callers
+/// transform coefficients (e.g. scalar doubling) without reaching into
+/// `poly.terms` directly.
+pub fn map_coefficients(poly: &MultiVarPolynomial, f: impl Fn(i32) -> i32) -> MultiVarPolynomial {
+    let mut result = MultiVarPolynomial::new(poly.num_vars, poly.modulus);
+    for (exponents, &coeff) in &poly.terms {
+        result.add_term(exponents.clone(), f(coeff).rem_euclid(poly.modulus));
+    }
+    result
+}
+
+/// Folds `f` over every coefficient of `poly`, starting from `init`. Lets
+/// callers compute aggregates (e.g. an L2 norm squared) without reaching
+/// into `poly.terms` directly.
+pub fn fold_coefficients<T>(poly: &MultiVarPolynomial, init: T, f: impl Fn(T, i32) -> T) -> T {
+    poly.terms.values().fold(init, |acc, &coeff| f(acc, coeff))
+}
+
+/// Trial-division primality test, sufficient for the field sizes this crate
+/// targets.
+///
+/// `is_prime(2)` is `true` (via the `n <= 3` branch below), so `GF(2)` is a
+/// valid modulus for [`MultiVarPolynomial::new`] like any other prime — the
+/// protocol, `bool_sum`, and `partial_eval` all work over it unmodified.
+/// One thing to keep in mind at modulus 2: `x^2` and `x` are the *same
+/// function* on boolean inputs (`0^2 == 0`, `1^2 == 1`), but not the same
+/// *polynomial* — this crate never rewrites `x^2` down to `x`, so a
+/// `degree_in_var` of 2 and a round-polynomial degree bound of 2 are both
+/// still enforced exactly as over any other field.
+pub fn is_prime(n: i32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n <= 3 {
+        return true;
+    }
+    if n % 2 == 0 {
         return false;
     }
     let mut i = 3;
@@ -235,12 +1357,680 @@ pub fn mod_inverse(a: i32, modulus: i32) -> Option<i32> {
     Some(old_s.rem_euclid(modulus as i64) as i32)
 }
 
+/// Inverts every element of `values` mod `modulus` with a single
+/// [`mod_inverse`] call plus `O(k)` multiplications, via Montgomery's
+/// batch inversion trick: build the running prefix products
+/// `prefix[i] = values[0] * ... * values[i]`, invert the final prefix
+/// product, then walk backwards recovering `values[i]^-1` as
+/// `inv_acc * prefix[i - 1]` and rolling `inv_acc` forward by `values[i]`.
+///
+/// Errors with [`PolyError::ZeroDenominator`] if any value has no inverse
+/// mod `modulus` (in particular, if any value is 0).
+pub fn batch_mod_inverse(values: &[i32], modulus: i32) -> Result<Vec<i32>, SumcheckError> {
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let modulus64 = modulus as i64;
+    let reduced: Vec<i64> = values.iter().map(|&v| v.rem_euclid(modulus) as i64).collect();
+
+    let mut prefix = Vec::with_capacity(reduced.len());
+    let mut acc = 1i64;
+    for &v in &reduced {
+        acc = (acc * v).rem_euclid(modulus64);
+        prefix.push(acc);
+    }
+
+    let mut inv_acc = mod_inverse(prefix[prefix.len() - 1] as i32, modulus)
+        .ok_or(PolyError::ZeroDenominator)? as i64;
+
+    let mut inverses = vec![0i32; reduced.len()];
+    for i in (0..reduced.len()).rev() {
+        let prefix_before = if i == 0 { 1 } else { prefix[i - 1] };
+        inverses[i] = ((inv_acc * prefix_before).rem_euclid(modulus64)) as i32;
+        inv_acc = (inv_acc * reduced[i]).rem_euclid(modulus64);
+    }
+
+    Ok(inverses)
+}
+
+/// Builds the univariate polynomial of degree `< points.len()` that passes
+/// through every `(x, y)` pair in `points`, via the standard Lagrange
+/// formula `L(x) = sum_i y_i * prod_{k != i} (x - x_k) / (x_i - x_k)`. The
+/// `prod` factors are built up as actual [`MultiVarPolynomial`]s via `*`,
+/// and the `degree` denominators are all inverted by one
+/// [`batch_mod_inverse`] call rather than `degree` separate ones.
+///
+/// Errors with [`PolyError::ZeroDenominator`] if any two points share an
+/// `x` coordinate (making that pair's denominator zero).
+pub fn lagrange_interpolate(points: &[(i32, i32)], modulus: i32) -> Result<MultiVarPolynomial, SumcheckError> {
+    let modulus64 = modulus as i64;
+    let denominators: Vec<i32> = points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x_i, _))| {
+            let product = points
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| k != i)
+                .fold(1i64, |acc, (_, &(x_k, _))| {
+                    (acc * (x_i as i64 - x_k as i64)).rem_euclid(modulus64)
+                });
+            product as i32
+        })
+        .collect();
+    let denom_inverses = batch_mod_inverse(&denominators, modulus)?;
+
+    let mut result = MultiVarPolynomial::new(1, modulus);
+    for (i, &(_, y_i)) in points.iter().enumerate() {
+        let mut basis = MultiVarPolynomial::new(1, modulus);
+        basis.add_term(vec![0], 1);
+        for (k, &(x_k, _)) in points.iter().enumerate() {
+            if k == i {
+                continue;
+            }
+            let mut factor = MultiVarPolynomial::new(1, modulus);
+            factor.add_term(vec![1], 1);
+            factor.add_term(vec![0], -x_k);
+            basis *= factor;
+        }
+        let scale = ((y_i as i64 * denom_inverses[i] as i64).rem_euclid(modulus64)) as i32;
+        basis *= scale;
+        result += basis;
+    }
+    Ok(result)
+}
+
+/// Samples a field element uniformly at random from `[0, modulus)`.
+/// Centralizes the `rng.gen_range(0..modulus)` pattern used throughout the
+/// protocol, so a future move to rejection sampling only has to change one
+/// place.
+pub fn random_field_element(modulus: i32, rng: &mut impl Rng) -> i32 {
+    rng.gen_range(0..modulus)
+}
+
+/// Evaluates `poly` at every point of the boolean hypercube `{0,1}^num_vars`,
+/// in the same order as [`HypercubeIter`] (variable `i` is bit `i` of the
+/// index, least-significant first). Used to build the bookkeeping table for
+/// [`OptimalProver`].
+///
+/// Errors if `poly.num_vars > 25`, for the same reason [`hypercube_sum`]
+/// does: `2^26` evaluations is already impractical for this naive
+/// enumeration, and the hypercube size can't be represented for much
+/// larger `num_vars` anyway.
+pub fn eval_hypercube(poly: &MultiVarPolynomial) -> Result<Vec<i32>, PolyError> {
+    if poly.num_vars > 25 {
+        return Err(PolyError::TooManyVariables { num_vars: poly.num_vars });
+    }
+    Ok(MultiVarPolynomial::hypercube_iter(poly.num_vars)
+        .map(|point| poly.evaluate(&point).expect("point has num_vars entries"))
+        .collect())
+}
+
+/// Inverse of [`eval_hypercube`]: builds the multilinear extension of
+/// `evals` (a table of `2^n` values over the boolean hypercube, in the
+/// same binary-counting order as [`MultiVarPolynomial::hypercube_iter`]) —
+/// the unique multilinear polynomial agreeing with `evals` on every
+/// hypercube point, expressed as a sum of [`eq_polynomial`] indicators
+/// scaled by each table entry.
+///
+/// Panics if `evals.len()` is not a power of two.
+pub fn from_hypercube_evals(evals: &[i32], modulus: i32) -> MultiVarPolynomial {
+    assert!(evals.len().is_power_of_two(), "evals length must be a power of two");
+    let num_vars = evals.len().trailing_zeros() as usize;
+    let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+    for (mask, &value) in evals.iter().enumerate() {
+        let bits: Vec<i32> = (0..num_vars).map(|i| ((mask >> i) & 1) as i32).collect();
+        let mut term = eq_polynomial(&bits, modulus);
+        term *= value;
+        poly += term;
+    }
+    poly
+}
+
+/// Computes the Hadamard (pointwise) product of two multilinear
+/// polynomials: the unique multilinear polynomial `h` with `h(x) = f(x) *
+/// g(x)` at every `x ∈ {0,1}^num_vars`. This differs from the algebraic
+/// product `f * g` (via [`Mul`]), which generally is not multilinear even
+/// when both operands are.
+///
+/// Errors if either polynomial is not multilinear, or if they don't share
+/// `num_vars`/`modulus`.
+pub fn hadamard_product_multilinear(
+    f: &MultiVarPolynomial,
+    g: &MultiVarPolynomial,
+) -> Result<MultiVarPolynomial, SumcheckError> {
+    if f.num_vars != g.num_vars {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: f.num_vars,
+            found: g.num_vars,
+        }));
+    }
+    if f.modulus != g.modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch {
+            left: f.modulus,
+            right: g.modulus,
+        }));
+    }
+    if !f.is_multilinear() || !g.is_multilinear() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "hadamard_product_multilinear requires both operands to be multilinear".to_string(),
+        ));
+    }
+
+    let f_evals = eval_hypercube(f)?;
+    let g_evals = eval_hypercube(g)?;
+    let modulus = f.modulus as i64;
+    let product_evals: Vec<i32> = f_evals
+        .iter()
+        .zip(g_evals.iter())
+        .map(|(&a, &b)| ((a as i64 * b as i64).rem_euclid(modulus)) as i32)
+        .collect();
+    Ok(from_hypercube_evals(&product_evals, f.modulus))
+}
+
+/// Builds the GKR sumcheck polynomial for one circuit layer: `f(x,y,z) =
+/// add(x,y,z)*(V(y) + V(z)) + mul(x,y,z)*(V(y)*V(z))`, where `V = v_i` is
+/// the multilinear extension of the previous (closer-to-output) layer's
+/// values, and `add_wiring`/`mul_wiring` are the wiring predicates'
+/// multilinear extensions. Summing the result over `(y,z) ∈ {0,1}^{2n}` is
+/// the quantity the GKR sumcheck proves equals `V_{i+1}(x)` (the next
+/// layer's own multilinear extension, evaluated at `x`).
+///
+/// `add_wiring` and `mul_wiring` must each have `3 * v_i.num_vars`
+/// variables -- `x`, `y`, and `z` each get `v_i.num_vars` of them, in that
+/// order -- and all three polynomials must share `modulus`.
+pub fn gkr_layer_poly(
+    v_i: &MultiVarPolynomial,
+    add_wiring: &MultiVarPolynomial,
+    mul_wiring: &MultiVarPolynomial,
+    modulus: i32,
+) -> Result<MultiVarPolynomial, SumcheckError> {
+    if v_i.modulus != modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch { left: modulus, right: v_i.modulus }));
+    }
+    if add_wiring.modulus != modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch { left: modulus, right: add_wiring.modulus }));
+    }
+    if mul_wiring.modulus != modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch { left: modulus, right: mul_wiring.modulus }));
+    }
+
+    let n = v_i.num_vars;
+    let total_vars = 3 * n;
+    if add_wiring.num_vars != total_vars {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: total_vars,
+            found: add_wiring.num_vars,
+        }));
+    }
+    if mul_wiring.num_vars != total_vars {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: total_vars,
+            found: mul_wiring.num_vars,
+        }));
+    }
+
+    let y_map: Vec<usize> = (0..n).map(|i| n + i).collect();
+    let z_map: Vec<usize> = (0..n).map(|i| 2 * n + i).collect();
+    let v_y = v_i.with_num_vars(total_vars, &y_map)?;
+    let v_z = v_i.with_num_vars(total_vars, &z_map)?;
+
+    let add_term = add_wiring.clone() * (v_y.clone() + v_z.clone());
+    let mul_term = mul_wiring.clone() * (v_y * v_z);
+    Ok(add_term + mul_term)
+}
+
+/// A single gate in a [`LayeredCircuit`] layer: combines two gates from
+/// the previous (closer-to-input) layer, referenced by index, via `+` or
+/// `*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateOp {
+    Add(usize, usize),
+    Mul(usize, usize),
+}
+
+/// A layered arithmetic circuit for the GKR protocol. Every layer --
+/// including the input layer -- has exactly `2^num_vars_per_layer` gates,
+/// matching the equal-width assumption [`gkr_layer_poly`] relies on.
+/// `gates[i]` describes layer `i + 1`'s gates in terms of layer `i`'s, so
+/// `gates.len()` is the number of non-input layers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayeredCircuit {
+    pub num_vars_per_layer: usize,
+    pub modulus: i32,
+    pub gates: Vec<Vec<GateOp>>,
+}
+
+impl LayeredCircuit {
+    /// Evaluates the circuit naively, layer by layer: `result[0]` is
+    /// `inputs`, and `result[k]` is `self.gates[k - 1]` applied to
+    /// `result[k - 1]`.
+    ///
+    /// Errors if `inputs` or any layer's gate list doesn't have exactly
+    /// `2^num_vars_per_layer` entries.
+    pub fn evaluate(&self, inputs: &[i32]) -> Result<Vec<Vec<i32>>, SumcheckError> {
+        let width = 1usize << self.num_vars_per_layer;
+        if inputs.len() != width {
+            return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+                expected: width,
+                found: inputs.len(),
+            }));
+        }
+
+        let mut layers = Vec::with_capacity(self.gates.len() + 1);
+        layers.push(inputs.to_vec());
+        for ops in &self.gates {
+            if ops.len() != width {
+                return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+                    expected: width,
+                    found: ops.len(),
+                }));
+            }
+            let prev = layers.last().expect("just pushed the input layer above");
+            let modulus = self.modulus as i64;
+            let next: Vec<i32> = ops
+                .iter()
+                .map(|op| match *op {
+                    GateOp::Add(l, r) => ((prev[l] as i64 + prev[r] as i64).rem_euclid(modulus)) as i32,
+                    GateOp::Mul(l, r) => ((prev[l] as i64 * prev[r] as i64).rem_euclid(modulus)) as i32,
+                })
+                .collect();
+            layers.push(next);
+        }
+        Ok(layers)
+    }
+
+    /// Builds the add/mul wiring predicates' multilinear extensions for
+    /// the transition from layer `layer_index` to layer `layer_index + 1`
+    /// (i.e. `self.gates[layer_index]`), in the `3 * num_vars_per_layer`
+    /// variable, `(x, y, z)`-block layout [`gkr_layer_poly`] expects:
+    /// `add_wiring`/`mul_wiring` are 1 at `(x, y, z)` exactly when gate
+    /// `x` of the next layer is `Add(y, z)`/`Mul(y, z)`, and 0 everywhere
+    /// else.
+    fn wiring_mles(&self, layer_index: usize) -> (MultiVarPolynomial, MultiVarPolynomial) {
+        let n = self.num_vars_per_layer;
+        let width = 1usize << n;
+        let mut add_evals = vec![0i32; width * width * width];
+        let mut mul_evals = vec![0i32; width * width * width];
+        for (x, op) in self.gates[layer_index].iter().enumerate() {
+            let (y, z, is_add) = match *op {
+                GateOp::Add(y, z) => (y, z, true),
+                GateOp::Mul(y, z) => (y, z, false),
+            };
+            let index = x | (y << n) | (z << (2 * n));
+            if is_add {
+                add_evals[index] = 1;
+            } else {
+                mul_evals[index] = 1;
+            }
+        }
+        (
+            from_hypercube_evals(&add_evals, self.modulus),
+            from_hypercube_evals(&mul_evals, self.modulus),
+        )
+    }
+}
+
+/// The GKR protocol's proof that a [`LayeredCircuit`] evaluates some
+/// (unrevealed) input to a claimed output: one sumcheck per layer, from
+/// the output layer down to the one just above the input, plus the two
+/// sub-evaluation claims (`V(r_y)`, `V(r_z)`) each layer's sumcheck ends
+/// with -- `layer_proofs[0]`/`layer_claims[0]` is the output-adjacent
+/// layer, `layer_proofs.last()`/`layer_claims.last()` is the
+/// input-adjacent one.
+///
+/// There is deliberately no `output_challenge` field here: both
+/// [`gkr_prove`] and [`gkr_verify`] derive it themselves, identically,
+/// from the (public) `claimed_output` via [`gkr_output_challenge`], so a
+/// malicious prover has no field to forge it through.
+///
+/// Soundness note: like the rest of this crate's GKR support, this
+/// follows a single path through the layer claims rather than the full
+/// construction's line-restriction reduction (see [`gkr_prove`]'s doc
+/// comment for what that means in practice).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GKRProof {
+    pub layer_proofs: Vec<SumcheckProof>,
+    pub layer_claims: Vec<(i32, i32)>,
+}
+
+/// Derives the output-layer challenge point `x` that both [`gkr_prove`]
+/// and [`gkr_verify`] fold the claimed output's multilinear extension at,
+/// via a [`Transcript`] seeded with `domain_separator` and bound to the
+/// public `claimed_output` -- so the point is unpredictable to a prover
+/// committing to a proof ahead of time, but independently reproducible by
+/// the verifier from public data alone, rather than read from the
+/// (attacker-controlled) proof.
+fn gkr_output_challenge(domain_separator: &str, claimed_output: &[i32], n: usize, modulus: i32) -> Vec<i32> {
+    let mut transcript = Transcript::new(domain_separator, n, modulus);
+    for &value in claimed_output {
+        transcript.append_scalar(value);
+    }
+    (0..n).map(|_| transcript.challenge_scalar(modulus)).collect()
+}
+
+/// Proves that `circuit` evaluates `inputs` to `claimed_output`, via one
+/// sumcheck per layer run against [`gkr_layer_poly`]'s `f(x, y, z)`.
+///
+/// Starting from the `output_challenge` point `x` [`gkr_output_challenge`]
+/// derives from `claimed_output` (so that checking the claimed output
+/// reduces to checking a single evaluation of its multilinear extension,
+/// by the Schwartz-Zippel lemma), each layer's sumcheck proves
+/// `V_{i+1}(x) = sum_{y,z} f_{i,V}(x,y,z)`, run non-interactively via
+/// [`prove_non_interactive`] under a per-layer domain separator derived
+/// from `domain_separator` -- so every challenge in the proof, output
+/// point included, is Fiat-Shamir-derived from public data and this
+/// layer's own committed round polynomials, never chosen by this
+/// function's caller or read back out of a proof. That sumcheck ends with
+/// two claims, `V_i(r_y)` and `V_i(r_z)`; this prover carries the chain
+/// down to the next (lower) layer via the `r_y` claim only, using it as
+/// that layer's `x`. The `r_z` claim is still checked -- [`gkr_verify`]
+/// verifies it against `V_i` exactly once, as part of that layer's own
+/// final check -- but it is not itself carried further down. A fully
+/// sound GKR prover additionally reduces both claims to a single point
+/// via a line-restriction polynomial before moving on; this one does not,
+/// so a cheating prover could still lie specifically about a `z`-branch
+/// value more than one layer removed from where it was made, without this
+/// proof catching it. See [`gkr_layer_poly`] for the per-layer relation
+/// and [`LayeredCircuit`] for the equal-layer-width assumption both this
+/// and `gkr_layer_poly` rely on.
+pub fn gkr_prove(
+    circuit: &LayeredCircuit,
+    inputs: &[i32],
+    claimed_output: &[i32],
+    domain_separator: &str,
+) -> Result<GKRProof, SumcheckError> {
+    let n = circuit.num_vars_per_layer;
+    let modulus = circuit.modulus;
+    let layers = circuit.evaluate(inputs)?;
+    if layers.last().expect("evaluate always returns at least the input layer").as_slice() != claimed_output {
+        return Err(SumcheckError::FinalCheckFailed);
+    }
+
+    let num_layers = circuit.gates.len();
+    let mut x_point = gkr_output_challenge(domain_separator, claimed_output, n, modulus);
+
+    let mut layer_proofs = Vec::with_capacity(num_layers);
+    let mut layer_claims = Vec::with_capacity(num_layers);
+
+    for layer_index in (0..num_layers).rev() {
+        let v_prev = from_hypercube_evals(&layers[layer_index], modulus);
+        let (add_wiring, mul_wiring) = circuit.wiring_mles(layer_index);
+        let f = gkr_layer_poly(&v_prev, &add_wiring, &mul_wiring, modulus)?;
+
+        let x_values: Vec<(usize, i32)> = x_point.iter().copied().enumerate().collect();
+        let fixed = f.partial_eval(&x_values)?;
+
+        let layer_domain = format!("{domain_separator}-gkr-layer-{layer_index}");
+        let proof = prove_non_interactive(&fixed, &layer_domain);
+
+        let r_y = &proof.challenges[..n];
+        let r_z = &proof.challenges[n..];
+        let claim_y = v_prev.evaluate(r_y)?;
+        let claim_z = v_prev.evaluate(r_z)?;
+
+        x_point = r_y.to_vec();
+        layer_proofs.push(proof);
+        layer_claims.push((claim_y, claim_z));
+    }
+
+    Ok(GKRProof {
+        layer_proofs,
+        layer_claims,
+    })
+}
+
+/// Verifies a [`GKRProof`] layer by layer, from the output layer down to
+/// the input layer, where `input_poly` is the multilinear extension of
+/// the circuit's (public) input layer. `domain_separator` must match the
+/// one `claimed_output` was proved under via [`gkr_prove`].
+///
+/// The output challenge is recomputed from `claimed_output` via
+/// [`gkr_output_challenge`] rather than trusted from the proof -- a
+/// [`GKRProof`] has no field to read it back out of in the first place.
+/// Each layer's sumcheck is checked the same way any sumcheck is (degree
+/// bound and running-sum consistency each round, via
+/// [`accumulate_challenges_via_transcript`] against a placeholder built
+/// from the layer's public wiring and a degree-1-in-every-variable
+/// stand-in for `V_i` -- the degree bound [`gkr_layer_poly`] produces
+/// doesn't depend on `V_i`'s actual values, only on the wiring's
+/// structure plus `V_i` being multilinear, so this never needs the
+/// (secret) witness) *and* every round's challenge is re-derived from a
+/// [`Transcript`] seeded the same way [`gkr_prove`]'s
+/// [`prove_non_interactive`] call derived it, rejecting if the proof's
+/// recorded challenge doesn't match -- so, as with
+/// [`verify_non_interactive`], this verifier never lets the proof pick
+/// its own challenges. Then the sumcheck's final round is checked against
+/// `add(x,r_y,r_z) * (V(r_y) + V(r_z)) + mul(x,r_y,r_z) * V(r_y) * V(r_z)`
+/// using the layer's public wiring and the proof's claimed `V(r_y)`,
+/// `V(r_z)` values. Once the loop reaches the input layer, its claims are
+/// additionally checked against `input_poly` directly, closing the
+/// soundness gap [`gkr_prove`]'s doc comment describes for that one
+/// layer.
+pub fn gkr_verify(
+    circuit: &LayeredCircuit,
+    input_poly: &MultiVarPolynomial,
+    claimed_output: &[i32],
+    proof: &GKRProof,
+    domain_separator: &str,
+) -> Result<(), SumcheckError> {
+    let n = circuit.num_vars_per_layer;
+    let modulus = circuit.modulus;
+    let num_layers = circuit.gates.len();
+
+    if proof.layer_proofs.len() != num_layers || proof.layer_claims.len() != num_layers {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: num_layers,
+            found: proof.layer_proofs.len(),
+        }));
+    }
+    if input_poly.num_vars != n || input_poly.modulus != modulus {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: n,
+            found: input_poly.num_vars,
+        }));
+    }
+
+    let output_challenge = gkr_output_challenge(domain_separator, claimed_output, n, modulus);
+    let output_mle = from_hypercube_evals(claimed_output, modulus);
+    let mut expected_claim = output_mle.evaluate(&output_challenge)?;
+    let mut x_point = output_challenge.clone();
+    // A degree-bound stand-in for `V_i`: an all-ones table would fold back
+    // to the constant polynomial `1` (degree 0 in every variable), which
+    // understates the real bound. `x_1 + ... + x_n` is genuinely degree 1
+    // in every variable instead, matching any real multilinear `V_i`.
+    let mut v_dummy = MultiVarPolynomial::new(n, modulus);
+    for var in 0..n {
+        let mut exponents = vec![0usize; n];
+        exponents[var] = 1;
+        v_dummy.add_term(exponents, 1);
+    }
+
+    for layer_index in (0..num_layers).rev() {
+        let idx = num_layers - 1 - layer_index;
+        let sumcheck_proof = &proof.layer_proofs[idx];
+        let (claim_y, claim_z) = proof.layer_claims[idx];
+
+        if sumcheck_proof.num_vars != 2 * n || sumcheck_proof.claimed_sum != expected_claim {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+
+        let (add_wiring, mul_wiring) = circuit.wiring_mles(layer_index);
+        let f_dummy = gkr_layer_poly(&v_dummy, &add_wiring, &mul_wiring, modulus)?;
+        let x_values: Vec<(usize, i32)> = x_point.iter().copied().enumerate().collect();
+        let fixed_dummy = f_dummy.partial_eval(&x_values)?;
+
+        let layer_domain = format!("{domain_separator}-gkr-layer-{layer_index}");
+        let mut transcript = Transcript::new(&layer_domain, sumcheck_proof.num_vars, modulus);
+        transcript.append_scalar(sumcheck_proof.claimed_sum);
+        let challenges = accumulate_challenges_via_transcript(&fixed_dummy, sumcheck_proof, &mut transcript)?;
+        let r_y = &challenges[..n];
+        let r_z = &challenges[n..];
+
+        let g_last = sumcheck_proof
+            .round_polys
+            .last()
+            .expect("num_vars == 2n > 0 so at least one round ran");
+        let last_challenge = *challenges.last().expect("2n > 0 so at least one challenge was drawn");
+        let final_value = g_last.evaluate(&[last_challenge])?;
+
+        let wiring_point: Vec<i32> = x_point.iter().chain(r_y.iter()).chain(r_z.iter()).copied().collect();
+        let add_at_final = add_wiring.evaluate(&wiring_point)?;
+        let mul_at_final = mul_wiring.evaluate(&wiring_point)?;
+        let modulus64 = modulus as i64;
+        let rhs = ((add_at_final as i64 * (claim_y as i64 + claim_z as i64)
+            + mul_at_final as i64 * claim_y as i64 * claim_z as i64)
+            .rem_euclid(modulus64)) as i32;
+        if final_value != rhs {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+
+        expected_claim = claim_y;
+        x_point = r_y.to_vec();
+
+        if layer_index == 0 && (claim_y != input_poly.evaluate(r_y)? || claim_z != input_poly.evaluate(r_z)?) {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+    }
+
+    if num_layers == 0 && input_poly.evaluate(&output_challenge)? != expected_claim {
+        return Err(SumcheckError::FinalCheckFailed);
+    }
+
+    Ok(())
+}
+
+/// A sumcheck prover for multilinear polynomials that runs in `O(n * 2^n)`
+/// total field operations, rather than the `O(n^2 * 2^n)` incurred by
+/// repeatedly calling [`MultiVarPolynomial::partial_eval`] and
+/// [`MultiVarPolynomial::bool_sum`] from scratch each round.
+///
+/// It maintains a bookkeeping table of the polynomial's evaluations over the
+/// boolean hypercube of the *remaining* variables. At round `j` the table has
+/// `2^(num_vars - j)` entries; [`OptimalProver::fold`] halves it in
+/// `O(2^(num_vars - j))` time by folding the current variable (the
+/// least-significant remaining index) into a single challenge point.
+pub struct OptimalProver {
+    pub table: Vec<i32>,
+    pub num_vars: usize,
+    pub round: usize,
+    pub modulus: i32,
+}
+
+impl OptimalProver {
+    /// Builds the initial evaluation table by enumerating the full boolean
+    /// hypercube of `poly`.
+    pub fn from_poly(poly: &MultiVarPolynomial) -> Self {
+        OptimalProver {
+            table: eval_hypercube(poly).expect("num_vars within eval_hypercube's practical enumeration bound"),
+            num_vars: poly.num_vars,
+            round: 0,
+            modulus: poly.modulus,
+        }
+    }
+
+    /// Computes the current round's polynomial `g(X)` by summing the table
+    /// over the remaining variables, holding the current variable fixed to 0
+    /// and then 1. Assumes `poly` is multilinear, so `g` is linear in `X`.
+    pub fn round_poly(&self) -> MultiVarPolynomial {
+        let half = self.table.len() / 2;
+        let (mut sum0, mut sum1) = (0i64, 0i64);
+        for pair in 0..half {
+            sum0 += self.table[2 * pair] as i64;
+            sum1 += self.table[2 * pair + 1] as i64;
+        }
+        let modulus = self.modulus as i64;
+        let g0 = sum0.rem_euclid(modulus) as i32;
+        let slope = ((sum1 - sum0).rem_euclid(modulus)) as i32;
+
+        let mut result = MultiVarPolynomial::new(1, self.modulus);
+        result.add_term(vec![0], g0);
+        result.add_term(vec![1], slope);
+        result
+    }
+
+    /// Folds the current variable into `challenge`, halving `table` in place:
+    /// each pair `(table[2i], table[2i+1])` becomes
+    /// `table[2i] + challenge * (table[2i+1] - table[2i])`.
+    pub fn fold(&mut self, challenge: i32) {
+        let half = self.table.len() / 2;
+        let modulus = self.modulus as i64;
+        let challenge = challenge as i64;
+        let mut folded = Vec::with_capacity(half);
+        for pair in 0..half {
+            let a = self.table[2 * pair] as i64;
+            let b = self.table[2 * pair + 1] as i64;
+            let value = (a + challenge * (b - a)).rem_euclid(modulus);
+            folded.push(value as i32);
+        }
+        self.table = folded;
+        self.round += 1;
+    }
+}
+
+/// Folds `poly`'s first variable to `challenge`, algebraically computing
+/// `(1 - challenge) * poly(0, x_1, ...) + challenge * poly(1, x_1, ...)`:
+/// splits each term by whether its first-variable exponent is 0 or 1,
+/// scales each half accordingly, and combines terms that land on the same
+/// remaining exponents. For a `poly` that is multilinear in its first
+/// variable, this is exactly the operation a sumcheck prover applies each
+/// round once the verifier's challenge for that round is known, and agrees
+/// with [`MultiVarPolynomial::partial_eval`] at `challenge` there.
+///
+/// Errors if `poly.num_vars == 0`, or if `poly`'s first-variable degree
+/// exceeds 1 (the splitting only accounts for exponents 0 and 1).
+pub fn fold_first_var(poly: &MultiVarPolynomial, challenge: i32) -> Result<MultiVarPolynomial, SumcheckError> {
+    if poly.num_vars == 0 {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: 1,
+            found: 0,
+        }));
+    }
+    if poly.degree_in_var(0) > 1 {
+        return Err(SumcheckError::UnsupportedOperation(
+            "fold_first_var requires poly to be linear in its first variable".to_string(),
+        ));
+    }
+
+    let modulus = poly.modulus as i64;
+    let challenge = (challenge as i64).rem_euclid(modulus);
+    let mut result = MultiVarPolynomial::new(poly.num_vars - 1, poly.modulus);
+    for (exponents, &coeff) in &poly.terms {
+        if coeff == 0 {
+            continue;
+        }
+        // Terms with exponent 0 in the first variable don't depend on it at
+        // all, so they pass through with weight `(1 - r) + r == 1`; only
+        // terms with exponent 1 pick up the challenge's weight.
+        let scaled = if exponents[0] == 0 {
+            coeff
+        } else {
+            ((coeff as i64 * challenge).rem_euclid(modulus)) as i32
+        };
+        result.add_term(exponents[1..].to_vec(), scaled);
+    }
+    Ok(result)
+}
+
 /// Computes the round polynomial `g_j` for the sumcheck protocol: the
 /// polynomial in `x_j` alone obtained by fixing `x_0..x_{j-1}` to
 /// `fixed_challenges` and summing over the boolean hypercube of the
 /// remaining variables.
-pub fn compute_g_j(polynomial: &MultiVarPolynomial, j: usize, fixed_challenges: &[i32]) -> MultiVarPolynomial {
+///
+/// Errors if more than 25 variables remain to be summed out, for the same
+/// reason [`hypercube_sum`] does: `2^26` terms is already impractical for
+/// this naive enumeration, and `1u64 << remaining_vars.len()` can't
+/// represent the hypercube size for much larger counts anyway.
+pub fn compute_g_j(
+    polynomial: &MultiVarPolynomial,
+    j: usize,
+    fixed_challenges: &[i32],
+) -> Result<MultiVarPolynomial, PolyError> {
     let remaining_vars: Vec<usize> = (j + 1..polynomial.num_vars).collect();
+    if remaining_vars.len() > 25 {
+        return Err(PolyError::TooManyVariables { num_vars: remaining_vars.len() });
+    }
     let degree = polynomial.degree_in_var(j);
     let mut coeffs = vec![0i32; degree + 1];
 
@@ -266,40 +2056,271 @@ pub fn compute_g_j(polynomial: &MultiVarPolynomial, j: usize, fixed_challenges:
             g.add_term(vec![exp], coeff);
         }
     }
-    g
+    Ok(g)
 }
 
-/// Runs the interactive sumcheck protocol for `polynomial`, printing a
-/// transcript of each round to stdout.
+/// Alternative to [`compute_g_j`] that derives the round polynomial from
+/// `deg_j + 1` evaluations, where `deg_j = polynomial.expected_round_degree(j)`,
+/// via [`lagrange_interpolate`], instead of deriving its coefficients
+/// symbolically. This is the shape a prover committed to an
+/// evaluation-only interface (e.g. one backed by a polynomial commitment
+/// scheme that only supports point openings) would take: it queries
+/// exactly as many points as the round's degree bound requires, so a
+/// polynomial whose degree varies from round to round (different
+/// variables having different degrees) gets the right number of queries
+/// automatically, rather than a fixed count sized for the worst case.
 ///
-/// `prover_overrides` and `verifier_overrides` let callers (mainly tests)
-/// substitute a round's honest message with an arbitrary one, to exercise
-/// the verifier's rejection paths.
-pub fn run_protocol(
-    polynomial: MultiVarPolynomial,
-    prover_overrides: HashMap<usize, MultiVarPolynomial>,
-    verifier_overrides: HashMap<usize, i32>,
-) -> Result<bool, SumcheckError> {
-    let modulus = polynomial.modulus;
-    let mut rng = rand::thread_rng();
+/// Errors under the same condition [`compute_g_j`] does: more than 25
+/// remaining variables.
+pub fn compute_g_j_by_evaluation(
+    polynomial: &MultiVarPolynomial,
+    j: usize,
+    fixed_challenges: &[i32],
+) -> Result<MultiVarPolynomial, SumcheckError> {
+    let remaining_vars = polynomial.num_vars - j - 1;
+    if remaining_vars > 25 {
+        return Err(SumcheckError::Poly(PolyError::TooManyVariables { num_vars: remaining_vars }));
+    }
 
-    let claimed_sum = polynomial.bool_sum();
-    println!("Prover claims sum C = {claimed_sum}");
+    let degree = polynomial.expected_round_degree(j);
+    let mut points = Vec::with_capacity(degree + 1);
+    for x in 0..=degree as i32 {
+        let mut values: Vec<(usize, i32)> = fixed_challenges.iter().enumerate().map(|(i, &r)| (i, r)).collect();
+        values.push((j, x));
+        let reduced = polynomial.partial_eval(&values)?;
+        points.push((x, reduced.bool_sum()));
+    }
+    lagrange_interpolate(&points, polynomial.modulus)
+}
 
-    let mut challenges: Vec<i32> = Vec::with_capacity(polynomial.num_vars);
-    let mut expected = claimed_sum;
+/// Rayon-backed alternative to [`compute_g_j`], for polynomials with
+/// enough remaining (summed-out) variables that splitting their boolean
+/// hypercube across threads outweighs the overhead. Each thread
+/// accumulates partial round-polynomial coefficients over its share of
+/// the hypercube; the partials are then summed mod `polynomial.modulus`,
+/// producing exactly the polynomial [`compute_g_j`] would compute serially.
+///
+/// Errors under the same condition [`compute_g_j`] does: more than 25
+/// remaining variables.
+#[cfg(feature = "parallel")]
+pub fn compute_g_j_parallel(
+    polynomial: &MultiVarPolynomial,
+    j: usize,
+    fixed_challenges: &[i32],
+) -> Result<MultiVarPolynomial, PolyError> {
+    use rayon::prelude::*;
 
-    for j in 0..polynomial.num_vars {
-        let g_j = match prover_overrides.get(&j) {
-            Some(poly) => poly.clone(),
-            None => compute_g_j(&polynomial, j, &challenges),
-        };
-        println!("Round {j}: prover sends g_{j} = {g_j:?}");
+    let remaining_vars: Vec<usize> = (j + 1..polynomial.num_vars).collect();
+    if remaining_vars.len() > 25 {
+        return Err(PolyError::TooManyVariables { num_vars: remaining_vars.len() });
+    }
+    let degree = polynomial.degree_in_var(j);
+    let modulus = polynomial.modulus;
+    let num_masks = 1u64 << remaining_vars.len();
 
-        let expected_degree = polynomial.degree_in_var(j);
-        let actual_degree = g_j.degree_in_var(0);
-        if actual_degree > expected_degree {
-            return Err(SumcheckError::DegreeCheckFailed {
+    let coeffs = (0..num_masks)
+        .into_par_iter()
+        .fold(
+            || vec![0i32; degree + 1],
+            |mut acc, mask| {
+                let mut values: Vec<(usize, i32)> = Vec::with_capacity(j + remaining_vars.len());
+                for (i, &r) in fixed_challenges.iter().enumerate() {
+                    values.push((i, r));
+                }
+                for (bit, &var) in remaining_vars.iter().enumerate() {
+                    values.push((var, ((mask >> bit) & 1) as i32));
+                }
+                let reduced = polynomial
+                    .partial_eval(&values)
+                    .expect("fixed values index within num_vars");
+                for (exponents, &coeff) in &reduced.terms {
+                    acc[exponents[0]] = (acc[exponents[0]] + coeff).rem_euclid(modulus);
+                }
+                acc
+            },
+        )
+        .reduce(
+            || vec![0i32; degree + 1],
+            |mut a, b| {
+                for (x, y) in a.iter_mut().zip(b.iter()) {
+                    *x = (*x + y).rem_euclid(modulus);
+                }
+                a
+            },
+        );
+
+    let mut g = MultiVarPolynomial::new(1, modulus);
+    for (exp, coeff) in coeffs.into_iter().enumerate() {
+        if coeff != 0 {
+            g.add_term(vec![exp], coeff);
+        }
+    }
+    Ok(g)
+}
+
+/// Incremental alternative to [`compute_g_j`] for polynomials of any
+/// degree (not just the multilinear case [`OptimalProver`] handles):
+/// instead of re-deriving each round from the original polynomial and
+/// every previously-bound challenge, it keeps the polynomial reduced by
+/// the challenges bound so far and folds in one more variable per round
+/// via [`partial_eval`](MultiVarPolynomial::partial_eval), so each round's
+/// work only depends on the remaining variables rather than all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamingProver {
+    pub poly: MultiVarPolynomial,
+    pub round: usize,
+}
+
+impl StreamingProver {
+    /// Starts streaming from the unreduced polynomial.
+    pub fn from_poly(poly: &MultiVarPolynomial) -> Self {
+        StreamingProver { poly: poly.clone(), round: 0 }
+    }
+
+    /// Computes the current round's polynomial `g(X)`: `self.poly` with
+    /// variable 0 left free, summed over the boolean hypercube of its
+    /// remaining variables — the same quantity [`compute_g_j`] computes
+    /// for the current round, but read off the already-reduced `self.poly`
+    /// instead of re-fixing every bound variable against the original one.
+    pub fn round_poly(&self) -> MultiVarPolynomial {
+        let remaining_vars: Vec<usize> = (1..self.poly.num_vars).collect();
+        let degree = self.poly.degree_in_var(0);
+        let mut coeffs = vec![0i32; degree + 1];
+
+        for mask in 0..(1u64 << remaining_vars.len()) {
+            let values: Vec<(usize, i32)> = remaining_vars
+                .iter()
+                .enumerate()
+                .map(|(bit, &var)| (var, ((mask >> bit) & 1) as i32))
+                .collect();
+            let reduced = self.poly.partial_eval(&values).expect("fixed values index within num_vars");
+            for (exponents, &coeff) in &reduced.terms {
+                coeffs[exponents[0]] = (coeffs[exponents[0]] + coeff).rem_euclid(self.poly.modulus);
+            }
+        }
+
+        let mut g = MultiVarPolynomial::new(1, self.poly.modulus);
+        for (exp, coeff) in coeffs.into_iter().enumerate() {
+            if coeff != 0 {
+                g.add_term(vec![exp], coeff);
+            }
+        }
+        g
+    }
+
+    /// Binds the current round's variable to `challenge`, replacing
+    /// `self.poly` with its partial evaluation — the remaining variables
+    /// renumbered down, exactly as [`partial_eval`](MultiVarPolynomial::partial_eval) does.
+    pub fn fold(&mut self, challenge: i32) {
+        self.poly = self
+            .poly
+            .partial_eval(&[(0, challenge)])
+            .expect("variable 0 always exists while rounds remain");
+        self.round += 1;
+    }
+}
+
+/// Per-round bookkeeping captured by [`run_protocol_silent`]: whether the
+/// round polynomial's degree and consistency checks passed, and which
+/// challenge the verifier sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundStat {
+    pub round: usize,
+    pub challenge: i32,
+    pub degree_ok: bool,
+    pub consistency_ok: bool,
+}
+
+/// The outcome of a full sumcheck run, as produced by [`run_protocol_silent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolResult {
+    pub accepted: bool,
+    pub claimed_sum: i32,
+    pub round_stats: Vec<RoundStat>,
+    pub final_eval_check: bool,
+}
+
+/// Estimates the serialized size in bytes of a full sumcheck proof for
+/// `poly`, without actually running the protocol. Each of the `num_vars`
+/// round polynomials is assumed to have `max_degree_per_var + 1` terms
+/// (one per possible exponent), and each term costs `bytes_per_term =
+/// num_vars * 2 + 4` bytes (2 bytes per exponent, 4 bytes per coefficient).
+///
+/// For the multilinear case (`max_degree_per_var == 1`), this reduces to
+/// `num_vars * 2 * (num_vars * 2 + 4)`, since every `g_j` has exactly 2
+/// terms.
+pub fn estimate_proof_bytes(poly: &MultiVarPolynomial) -> usize {
+    let max_degree_per_var = poly.degree_summary().per_var.into_iter().max().unwrap_or(0);
+    let bytes_per_term = poly.num_vars * 2 + 4;
+    let terms_per_round = max_degree_per_var + 1;
+    poly.num_vars * terms_per_round * bytes_per_term
+}
+
+/// Estimates the number of field multiplications the naive prover (the one
+/// behind [`compute_g_j`], re-deriving each round from scratch) performs
+/// over the whole protocol, using the `O(n^2 * 2^n)` formula: `n` rounds,
+/// each re-evaluating the polynomial at `O(n * 2^n)` hypercube points.
+pub fn estimate_prover_field_ops(poly: &MultiVarPolynomial) -> u64 {
+    let n = poly.num_vars as u64;
+    n * n * (1u64 << poly.num_vars)
+}
+
+/// Performs the sumcheck protocol's final oracle check in isolation:
+/// whether `claimed` matches `polynomial` evaluated at `point` (the
+/// accumulated verifier challenges). [`run_protocol_silent`] and
+/// [`SumcheckProof::self_check`] both perform this same check inline
+/// against a [`MultiVarPolynomial`] they hold directly; exposing it as a
+/// standalone function lets a caller with a polynomial commitment scheme
+/// substitute their own opening/evaluation oracle for `claimed` in place
+/// of direct evaluation, without needing the rest of the protocol's
+/// bookkeeping.
+///
+/// Returns `false` (rather than propagating a [`PolyError`]) if `point`
+/// doesn't have `polynomial.num_vars` entries, since a malformed point is
+/// simply a failed check from the caller's point of view.
+pub fn check_final_eval(polynomial: &MultiVarPolynomial, point: &[i32], claimed: i32) -> bool {
+    matches!(polynomial.evaluate(point), Ok(actual) if actual == claimed)
+}
+
+/// Runs the interactive sumcheck protocol for `polynomial` without printing
+/// anything, returning a [`ProtocolResult`] with the full transcript of
+/// round-by-round checks. This is the library-friendly counterpart to
+/// [`run_protocol`], which wraps this function and prints its result.
+///
+/// `prover_overrides` and `verifier_overrides` let callers (mainly tests)
+/// substitute a round's honest message with an arbitrary one, to exercise
+/// the verifier's rejection paths.
+///
+/// The prover's claimed sum `C` can be independently checked with
+/// [`hypercube_sum`], which is the reference (unoptimized) implementation
+/// of the same quantity.
+pub fn run_protocol_silent(
+    polynomial: MultiVarPolynomial,
+    prover_overrides: BTreeMap<usize, MultiVarPolynomial>,
+    verifier_overrides: BTreeMap<usize, i32>,
+) -> Result<ProtocolResult, SumcheckError> {
+    let modulus = polynomial.modulus;
+    let mut rng = rand::thread_rng();
+
+    let claimed_sum = polynomial.bool_sum();
+
+    let mut challenges: Vec<i32> = Vec::with_capacity(polynomial.num_vars);
+    let mut round_stats = Vec::with_capacity(polynomial.num_vars);
+    let mut expected = claimed_sum;
+
+    for j in 0..polynomial.num_vars {
+        let g_j = match prover_overrides.get(&j) {
+            Some(poly) => poly.clone(),
+            None => compute_g_j(&polynomial, j, &challenges)?,
+        };
+
+        let expected_degree = polynomial.expected_round_degree(j);
+        let actual_degree = g_j.degree_in_var(0);
+        let degree_ok = actual_degree <= expected_degree;
+        if !degree_ok {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(round = j, expected = expected_degree, found = actual_degree, "degree check failed");
+            return Err(SumcheckError::DegreeCheckFailed {
                 round: j,
                 expected: expected_degree,
                 found: actual_degree,
@@ -308,9 +2329,14 @@ pub fn run_protocol(
 
         let g_j_at_0 = g_j.evaluate(&[0])?;
         let g_j_at_1 = g_j.evaluate(&[1])?;
-        if (g_j_at_0 + g_j_at_1).rem_euclid(modulus) != expected {
+        let consistency_ok = (g_j_at_0 + g_j_at_1).rem_euclid(modulus) == expected;
+        if !consistency_ok {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(round = j, consistency_check = consistency_ok, "consistency check failed");
             return Err(SumcheckError::ConsistencyCheckFailed { round: j });
         }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(round = j, degree = actual_degree, consistency_check = consistency_ok, "round checks passed");
 
         let r_j = match verifier_overrides.get(&j) {
             Some(&value) => {
@@ -319,34 +2345,4131 @@ pub fn run_protocol(
                 }
                 value
             }
-            None => rng.gen_range(0..modulus),
+            None => random_field_element(modulus, &mut rng),
         };
-        println!("Round {j}: verifier sends challenge r_{j} = {r_j}");
+        #[cfg(feature = "tracing")]
+        tracing::info!(round = j, challenge = r_j, "verifier sent challenge");
 
         expected = g_j.evaluate(&[r_j])?;
         challenges.push(r_j);
+        round_stats.push(RoundStat { round: j, challenge: r_j, degree_ok, consistency_ok });
     }
 
     let final_eval = polynomial.evaluate(&challenges)?;
-    if final_eval != expected {
+    let final_eval_check = final_eval == expected;
+    if !final_eval_check {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("final oracle check failed");
         return Err(SumcheckError::FinalCheckFailed);
     }
 
-    println!("Verifier accepts the proof.");
-    Ok(true)
+    Ok(ProtocolResult {
+        accepted: true,
+        claimed_sum,
+        round_stats,
+        final_eval_check,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Verifies several sumcheck claims at once, all driven by the same
+/// challenges `r_1, ..., r_n` rather than each drawing its own — so a
+/// verifier checking `k` claims over `n`-variable polynomials spends `n`
+/// random field elements total instead of `k * n`, which matters when
+/// recursively composing many sumcheck instances.
+///
+/// `polys[i]` must claim sum `claimed_sums[i]` over its boolean hypercube,
+/// and must have exactly `shared_challenges.len()` variables, since each
+/// round `j` reuses `shared_challenges[j]` as every polynomial's
+/// challenge. Returns one `bool` per polynomial: whether its claimed sum,
+/// round-by-round checks, and final oracle evaluation (at the shared
+/// challenge point) all held.
+///
+/// Errors if `polys` and `claimed_sums` have different lengths, or if any
+/// polynomial doesn't have exactly `shared_challenges.len()` variables.
+pub fn shared_sumcheck(
+    polys: &[MultiVarPolynomial],
+    claimed_sums: &[i32],
+    shared_challenges: &[i32],
+) -> Result<Vec<bool>, SumcheckError> {
+    if polys.len() != claimed_sums.len() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "polys and claimed_sums must have the same length".to_string(),
+        ));
+    }
 
-    #[test]
-    fn test_add_term() {
-        let mut poly = MultiVarPolynomial::new(2, 5);
-        poly.add_term(vec![1, 0], 3);
-        poly.add_term(vec![1, 0], 2);
-        // (3 + 2) % 5 == 0, so the term cancels out.
-        assert_eq!(poly.terms.get(&vec![1, 0]), Some(&0));
+    let verifier_overrides: BTreeMap<usize, i32> =
+        shared_challenges.iter().copied().enumerate().collect();
+
+    let mut accepted = Vec::with_capacity(polys.len());
+    for (poly, &claimed_sum) in polys.iter().zip(claimed_sums) {
+        if poly.num_vars != shared_challenges.len() {
+            return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+                expected: shared_challenges.len(),
+                found: poly.num_vars,
+            }));
+        }
+        let matches_claim = poly.bool_sum() == claimed_sum;
+        let result = run_protocol_silent(poly.clone(), BTreeMap::new(), verifier_overrides.clone());
+        accepted.push(matches_claim && matches!(result, Ok(r) if r.accepted));
+    }
+    Ok(accepted)
+}
+
+/// Renders `proof`'s round-by-round transcript as a human-readable ASCII
+/// table: each round's degree, the previous round's claimed value, this
+/// round's `g_j(0) + g_j(1)`, the challenge sent, and whether the two
+/// values agree. Meant for tracing exactly which round a proof fails in
+/// during debugging or a classroom walkthrough, not for machine parsing.
+pub fn transcript_to_ascii_table(proof: &SumcheckProof) -> String {
+    let mut table = format!(
+        "{:<5} | {:<10} | {:<10} | {:<13} | {:<13} | {:<5}\n",
+        "Round", "g_j degree", "g_{j-1}(r)", "g_j(0)+g_j(1)", "Challenge r_j", "Check"
+    );
+    table.push_str("------+------------+------------+---------------+---------------+------\n");
+
+    let mut previous_label = format!("C={}", proof.claimed_sum);
+    let mut previous_value = proof.claimed_sum;
+    for (j, g_j) in proof.round_polys.iter().enumerate() {
+        let degree = g_j.degree_in_var(0);
+        let g_j_at_0 = g_j.evaluate(&[0]).unwrap_or(0);
+        let g_j_at_1 = g_j.evaluate(&[1]).unwrap_or(0);
+        let sum = (g_j_at_0 + g_j_at_1).rem_euclid(proof.modulus);
+        let challenge = proof.challenges[j];
+        let check = if sum == previous_value { "\u{2713}" } else { "\u{2717}" };
+
+        table.push_str(&format!(
+            "{:<5} | {:<10} | {:<10} | {:<13} | {:<13} | {:<5}\n",
+            j + 1,
+            degree,
+            previous_label,
+            sum,
+            challenge,
+            check
+        ));
+
+        previous_value = g_j.evaluate(&[challenge]).unwrap_or(0);
+        previous_label = format!("g_{}({})={}", j + 1, challenge, previous_value);
+    }
+    table
+}
+
+/// Renders `poly`'s terms as a bare LaTeX math expression, without the
+/// surrounding `$...$` -- the shared body [`poly_to_latex`] and
+/// [`proof_to_latex`] both build on.
+///
+/// Precondition: if `var_names` is `Some`, it must have `poly.num_vars`
+/// entries (panics via slice indexing otherwise, same as passing a
+/// mis-sized point to [`MultiVarPolynomial::evaluate`] would panic rather
+/// than error for a purely cosmetic helper).
+fn poly_to_latex_body(poly: &MultiVarPolynomial, var_names: Option<&[&str]>) -> String {
+    let mut normalized = poly.clone();
+    normalized.normalize();
+
+    if normalized.terms.is_empty() {
+        return "0".to_string();
+    }
+
+    let mut body = String::new();
+    for (exponents, &coeff) in &normalized.terms {
+        // A coefficient past the midpoint is shorter to write as its
+        // negative residue (e.g. `modulus - 1` as `-1`) -- same value mod
+        // `modulus`, just more readable in a paper or course note.
+        let signed_coeff = if coeff > poly.modulus / 2 { coeff - poly.modulus } else { coeff };
+        let is_constant = exponents.iter().all(|&exp| exp == 0);
+
+        if body.is_empty() {
+            if signed_coeff < 0 {
+                body.push('-');
+            }
+        } else {
+            body.push_str(if signed_coeff < 0 { " - " } else { " + " });
+        }
+
+        let magnitude = signed_coeff.unsigned_abs();
+        if is_constant || magnitude != 1 {
+            body.push_str(&magnitude.to_string());
+        }
+
+        for (i, &exp) in exponents.iter().enumerate() {
+            if exp == 0 {
+                continue;
+            }
+            match var_names {
+                Some(names) => body.push_str(names[i]),
+                None => body.push_str(&format!("x_{i}")),
+            }
+            if exp > 1 {
+                body.push_str(&format!("^{{{exp}}}"));
+            }
+        }
+    }
+    body
+}
+
+/// Renders `poly` as a standalone LaTeX expression, e.g. `$3x_0^{2}x_1 +
+/// x_2 + 7$`, for dropping directly into academic papers or course notes.
+/// Variable names come from `var_names` if given, otherwise `x_0, x_1,
+/// ...`.
+pub fn poly_to_latex(poly: &MultiVarPolynomial, var_names: Option<&[&str]>) -> String {
+    format!("${}$", poly_to_latex_body(poly, var_names))
+}
+
+/// Renders `proof`'s round polynomials as an `align*` environment, one
+/// line per round (`g_1(x) &= ... \\`, `g_2(x) &= ... \\`, ...), for the
+/// same academic-paper/course-note use case as [`poly_to_latex`].
+pub fn proof_to_latex(proof: &SumcheckProof) -> String {
+    let mut body = String::from("\\begin{align*}\n");
+    for (j, g_j) in proof.round_polys.iter().enumerate() {
+        let rendered = poly_to_latex_body(g_j, Some(&["x"]));
+        body.push_str(&format!("g_{{{}}}(x) &= {} \\\\\n", j + 1, rendered));
+    }
+    body.push_str("\\end{align*}\n");
+    body
+}
+
+/// Renders `poly`'s terms as CSV: a header line `coefficient,var_0,...,
+/// var_{n-1}`, followed by one `coeff,exp0,exp1,...` line per non-zero
+/// term, for loading into spreadsheets or tabular tools (e.g. pandas)
+/// that would otherwise need to parse the JSON [`test_vector`] format.
+pub fn poly_to_csv(poly: &MultiVarPolynomial) -> String {
+    let mut csv = String::from("coefficient");
+    for i in 0..poly.num_vars {
+        csv.push_str(&format!(",var_{i}"));
+    }
+    csv.push('\n');
+
+    for (exponents, &coeff) in &poly.terms {
+        if coeff == 0 {
+            continue;
+        }
+        csv.push_str(&coeff.to_string());
+        for &exp in exponents {
+            csv.push(',');
+            csv.push_str(&exp.to_string());
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Inverse of [`poly_to_csv`]: reconstructs a polynomial with `num_vars`
+/// variables over `modulus` from its CSV rendering, skipping the header
+/// line. Terms with coefficient `0` are simply never added, matching
+/// [`poly_to_csv`]'s omission of them.
+pub fn poly_from_csv(csv: &str, num_vars: usize, modulus: i32) -> Result<MultiVarPolynomial, PolyError> {
+    let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+    for line in csv.lines().skip(1) {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let parts: Vec<i32> = line
+            .split(',')
+            .map(|s| s.trim().parse().map_err(|_| PolyError::ParseError(format!("invalid CSV field: {s}"))))
+            .collect::<Result<Vec<i32>, PolyError>>()?;
+        if parts.len() != num_vars + 1 {
+            return Err(PolyError::ParseError(format!(
+                "expected {} fields, found {}",
+                num_vars + 1,
+                parts.len()
+            )));
+        }
+        let (coeff, exponents) = (parts[0], &parts[1..]);
+        let exponents: Vec<usize> = exponents.iter().map(|&e| e as usize).collect();
+        if coeff != 0 {
+            poly.add_term(exponents, coeff);
+        }
+    }
+    Ok(poly)
+}
+
+/// Binary format version [`poly_to_bytes`] currently writes and
+/// [`poly_from_bytes`] accepts. Bumping this lets a future format change
+/// coexist with decoders for the old one.
+const POLY_BYTES_VERSION: u8 = 0x01;
+
+/// Encodes `poly` into a compact binary format: 1 byte version
+/// ([`POLY_BYTES_VERSION`]), 4 bytes `num_vars` (little-endian `u32`), 4
+/// bytes `modulus` (little-endian `i32`), 4 bytes `num_terms` (little-endian
+/// `u32`), then for each non-zero term `num_vars` little-endian `u16`
+/// exponents (so a single variable's degree up to 65535) followed by the
+/// term's little-endian `i32` coefficient. Smaller and faster to parse than
+/// the JSON [`test_vector`] format or the CSV of [`poly_to_csv`].
+pub fn poly_to_bytes(poly: &MultiVarPolynomial) -> Vec<u8> {
+    let terms: Vec<(&Vec<usize>, &i32)> = poly.terms.iter().filter(|(_, &coeff)| coeff != 0).collect();
+
+    let mut bytes = Vec::with_capacity(13 + terms.len() * (poly.num_vars * 2 + 4));
+    bytes.push(POLY_BYTES_VERSION);
+    bytes.extend_from_slice(&(poly.num_vars as u32).to_le_bytes());
+    bytes.extend_from_slice(&poly.modulus.to_le_bytes());
+    bytes.extend_from_slice(&(terms.len() as u32).to_le_bytes());
+    for (exponents, &coeff) in terms {
+        for &exp in exponents {
+            bytes.extend_from_slice(&(exp as u16).to_le_bytes());
+        }
+        bytes.extend_from_slice(&coeff.to_le_bytes());
+    }
+    bytes
+}
+
+/// Inverse of [`poly_to_bytes`]. Bounds-checks every field, so truncated or
+/// corrupted input errors instead of panicking.
+pub fn poly_from_bytes(bytes: &[u8]) -> Result<MultiVarPolynomial, PolyError> {
+    fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], PolyError> {
+        if bytes.len() < len {
+            return Err(PolyError::ParseError(format!(
+                "expected {len} more bytes, found {}",
+                bytes.len()
+            )));
+        }
+        let (head, tail) = bytes.split_at(len);
+        *bytes = tail;
+        Ok(head)
+    }
+
+    let mut cursor = bytes;
+    let version = take(&mut cursor, 1)?[0];
+    if version != POLY_BYTES_VERSION {
+        return Err(PolyError::ParseError(format!("unsupported format version: {version}")));
+    }
+    let num_vars = u32::from_le_bytes(take(&mut cursor, 4)?.try_into().unwrap()) as usize;
+    let modulus = i32::from_le_bytes(take(&mut cursor, 4)?.try_into().unwrap());
+    let num_terms = u32::from_le_bytes(take(&mut cursor, 4)?.try_into().unwrap());
+    if !is_prime(modulus) {
+        return Err(PolyError::InvalidModulus(modulus));
+    }
+
+    let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+    for _ in 0..num_terms {
+        let mut exponents = Vec::with_capacity(num_vars);
+        for _ in 0..num_vars {
+            exponents.push(u16::from_le_bytes(take(&mut cursor, 2)?.try_into().unwrap()) as usize);
+        }
+        let coeff = i32::from_le_bytes(take(&mut cursor, 4)?.try_into().unwrap());
+        poly.add_term(exponents, coeff);
+    }
+    Ok(poly)
+}
+
+/// A complete, independently-replayable sumcheck proof: the claimed sum,
+/// every round polynomial the honest prover sent, and the verifier
+/// challenges that produced them, as built by [`prove_hypercube_sum`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SumcheckProof {
+    pub num_vars: usize,
+    pub modulus: i32,
+    pub claimed_sum: i32,
+    pub round_polys: Vec<MultiVarPolynomial>,
+    pub challenges: Vec<i32>,
+}
+
+/// Replays `proof.round_polys`' degree and consistency checks against
+/// `poly`, round by round, collecting the challenge sequence along the
+/// way: round `j` uses `proof.challenges[j]` if `proof` already carries
+/// one, otherwise draws a fresh one via `rng`. This is the shared core of
+/// both [`SumcheckProof::self_check`] (which always has every challenge
+/// already recorded) and an interactive verifier replaying a partially
+/// interactive proof (which doesn't).
+///
+/// Errors the same way [`run_protocol_silent`]'s loop does: a
+/// [`SumcheckError::DegreeCheckFailed`] or
+/// [`SumcheckError::ConsistencyCheckFailed`] at the first round that fails
+/// either check.
+pub fn accumulate_challenges(
+    poly: &MultiVarPolynomial,
+    proof: &SumcheckProof,
+    rng: &mut impl Rng,
+) -> Result<Vec<i32>, SumcheckError> {
+    let mut expected = proof.claimed_sum;
+    let mut challenges = Vec::with_capacity(proof.num_vars);
+    for j in 0..proof.num_vars {
+        let g_j = &proof.round_polys[j];
+
+        let expected_degree = poly.expected_round_degree(j);
+        let actual_degree = g_j.degree_in_var(0);
+        if actual_degree > expected_degree {
+            return Err(SumcheckError::DegreeCheckFailed {
+                round: j,
+                expected: expected_degree,
+                found: actual_degree,
+            });
+        }
+
+        let g_j_at_0 = g_j.evaluate(&[0])?;
+        let g_j_at_1 = g_j.evaluate(&[1])?;
+        if (g_j_at_0 + g_j_at_1).rem_euclid(proof.modulus) != expected {
+            return Err(SumcheckError::ConsistencyCheckFailed { round: j });
+        }
+
+        let r_j = proof
+            .challenges
+            .get(j)
+            .copied()
+            .unwrap_or_else(|| random_field_element(proof.modulus, rng));
+        expected = g_j.evaluate(&[r_j])?;
+        challenges.push(r_j);
+    }
+    Ok(challenges)
+}
+
+/// Like [`accumulate_challenges`], but for a verifier that must not trust
+/// `proof.challenges` at all (because, unlike [`SumcheckProof::self_check`]'s
+/// caller, it didn't produce this proof and has no independent rng-based
+/// interactive exchange with whoever did): round `j`'s challenge is always
+/// re-derived from `transcript` via the same `append_poly`-then-
+/// `challenge_scalar` sequence [`prove_non_interactive`] used to produce
+/// it, and rejected with [`SumcheckError::FinalCheckFailed`] if it doesn't
+/// match `proof.challenges[j]`. This is [`gkr_verify`]'s per-layer
+/// equivalent of what [`verify_non_interactive`] does for a plain
+/// sumcheck proof.
+fn accumulate_challenges_via_transcript(
+    poly: &MultiVarPolynomial,
+    proof: &SumcheckProof,
+    transcript: &mut Transcript,
+) -> Result<Vec<i32>, SumcheckError> {
+    let mut expected = proof.claimed_sum;
+    let mut challenges = Vec::with_capacity(proof.num_vars);
+    for j in 0..proof.num_vars {
+        let g_j = &proof.round_polys[j];
+
+        let expected_degree = poly.expected_round_degree(j);
+        let actual_degree = g_j.degree_in_var(0);
+        if actual_degree > expected_degree {
+            return Err(SumcheckError::DegreeCheckFailed {
+                round: j,
+                expected: expected_degree,
+                found: actual_degree,
+            });
+        }
+
+        let g_j_at_0 = g_j.evaluate(&[0])?;
+        let g_j_at_1 = g_j.evaluate(&[1])?;
+        if (g_j_at_0 + g_j_at_1).rem_euclid(proof.modulus) != expected {
+            return Err(SumcheckError::ConsistencyCheckFailed { round: j });
+        }
+
+        transcript.append_poly(g_j);
+        let r_j = transcript.challenge_scalar(proof.modulus);
+        if proof.challenges.get(j) != Some(&r_j) {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+
+        expected = g_j.evaluate(&[r_j])?;
+        challenges.push(r_j);
+    }
+    Ok(challenges)
+}
+
+/// Performs only the final oracle check: `poly` evaluated at `challenges`
+/// must match `g_last` (the last round polynomial) evaluated at the last
+/// challenge. Split out from [`accumulate_challenges`] so a verifier
+/// backed by a commitment scheme can swap this direct evaluation of
+/// `poly` for an opening check against a commitment, while still reusing
+/// [`accumulate_challenges`] for everything before it.
+pub fn final_oracle_check(
+    poly: &MultiVarPolynomial,
+    g_last: &MultiVarPolynomial,
+    challenges: &[i32],
+) -> Result<(), SumcheckError> {
+    let last_challenge = *challenges
+        .last()
+        .ok_or(SumcheckError::Poly(PolyError::EmptyInput))?;
+    let g_last_eval = g_last.evaluate(&[last_challenge])?;
+    let final_eval = poly.evaluate(challenges)?;
+    if final_eval != g_last_eval {
+        return Err(SumcheckError::FinalCheckFailed);
+    }
+    Ok(())
+}
+
+impl SumcheckProof {
+    /// Runs the same degree and consistency checks [`run_protocol_silent`]'s
+    /// verifier would, against this proof's own `round_polys` and
+    /// `challenges` rather than freshly-drawn ones (via
+    /// [`accumulate_challenges`]), plus the final oracle check against
+    /// `polynomial` (via [`final_oracle_check`]). Lets an honest prover
+    /// validate its own proof before sending it, instead of discovering a
+    /// bug on the verifier's side.
+    pub fn self_check(&self, polynomial: &MultiVarPolynomial) -> Result<(), SumcheckError> {
+        let mut rng = rand::thread_rng();
+        let challenges = accumulate_challenges(polynomial, self, &mut rng)?;
+        match self.round_polys.last() {
+            Some(g_last) => final_oracle_check(polynomial, g_last, &challenges),
+            None => {
+                let final_eval = polynomial.evaluate(&challenges)?;
+                if final_eval != self.claimed_sum {
+                    return Err(SumcheckError::FinalCheckFailed);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Reports every failing check against this proof's own `round_polys`
+    /// and `challenges`, rather than stopping at the first one the way
+    /// [`SumcheckProof::self_check`] does -- useful while debugging a
+    /// manually-constructed transcript where more than one invariant might
+    /// be broken at once. Each entry is a human-readable line such as
+    /// `"round 2: degree 4 exceeds bound 3"` or `"round 3: consistency
+    /// mismatch, expected 5 got 9"`. An empty `Vec` means the proof is
+    /// valid.
+    pub fn diagnose(&self, polynomial: &MultiVarPolynomial) -> Vec<String> {
+        let mut issues = Vec::new();
+        if self.round_polys.len() != self.num_vars || self.challenges.len() != self.num_vars {
+            issues.push(format!(
+                "proof shape mismatch: expected {} rounds, found {} round polynomials and {} challenges",
+                self.num_vars,
+                self.round_polys.len(),
+                self.challenges.len()
+            ));
+            return issues;
+        }
+
+        let mut expected = self.claimed_sum;
+        for j in 0..self.num_vars {
+            let g_j = &self.round_polys[j];
+
+            let expected_degree = polynomial.expected_round_degree(j);
+            let actual_degree = g_j.degree_in_var(0);
+            if actual_degree > expected_degree {
+                issues.push(format!("round {j}: degree {actual_degree} exceeds bound {expected_degree}"));
+            }
+
+            let (g_j_at_0, g_j_at_1) = match (g_j.evaluate(&[0]), g_j.evaluate(&[1])) {
+                (Ok(a), Ok(b)) => (a, b),
+                _ => {
+                    issues.push(format!("round {j}: could not evaluate round polynomial at 0/1"));
+                    continue;
+                }
+            };
+            let actual_sum = (g_j_at_0 + g_j_at_1).rem_euclid(self.modulus);
+            if actual_sum != expected {
+                issues.push(format!("round {j}: consistency mismatch, expected {expected} got {actual_sum}"));
+            }
+
+            expected = match g_j.evaluate(&[self.challenges[j]]) {
+                Ok(value) => value,
+                Err(_) => {
+                    issues.push(format!("round {j}: could not evaluate round polynomial at its challenge"));
+                    continue;
+                }
+            };
+        }
+
+        match polynomial.evaluate(&self.challenges) {
+            Ok(final_eval) if final_eval != expected => {
+                issues.push(format!("final oracle check failed: expected {expected} got {final_eval}"));
+            }
+            Err(_) => issues.push("final oracle check failed: could not evaluate polynomial at challenges".to_string()),
+            _ => {}
+        }
+
+        issues
+    }
+
+    /// Prints [`transcript_to_ascii_table`]'s rendering of this proof to
+    /// stdout, for quick visual tracing of which round a proof fails in.
+    ///
+    /// Requires the `std` feature, since it prints directly; `no_std`
+    /// callers can call [`transcript_to_ascii_table`] themselves and do
+    /// whatever they like with the resulting `String`.
+    #[cfg(feature = "std")]
+    pub fn print_transcript(&self) {
+        println!("{}", transcript_to_ascii_table(self));
+    }
+}
+
+/// The running state [`StreamingVerifier`] carries between rounds: the
+/// current round index, the running `expected` value each round
+/// polynomial must sum to, and the challenges sent so far (which the
+/// final oracle check needs, following the same caller-accumulates
+/// convention as [`compute_g_j`]'s `fixed_challenges`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierInternalState {
+    pub round: usize,
+    pub expected: i32,
+    pub challenges: Vec<i32>,
+}
+
+/// A sumcheck verifier that checks one round at a time instead of
+/// requiring the whole proof up front, so a caller streaming round
+/// polynomials from disk never needs to hold more than one of them in
+/// memory at once.
+///
+/// [`process_round`](Self::process_round) replays the same degree and
+/// consistency checks as [`run_protocol_silent`]'s loop body, and
+/// [`finalize`](Self::finalize) replays its final oracle check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamingVerifier {
+    pub poly: MultiVarPolynomial,
+    pub claimed_sum: i32,
+    pub state: VerifierInternalState,
+}
+
+impl StreamingVerifier {
+    /// Starts a fresh streaming verification of `poly`'s claimed sum over
+    /// the boolean hypercube.
+    pub fn new(poly: MultiVarPolynomial) -> Self {
+        let claimed_sum = poly.bool_sum();
+        StreamingVerifier {
+            poly,
+            claimed_sum,
+            state: VerifierInternalState { round: 0, expected: claimed_sum, challenges: Vec::new() },
+        }
+    }
+
+    /// Checks round polynomial `g` against the current round's degree
+    /// bound and the running `expected` value, then selects this round's
+    /// challenge — `challenge` if given, otherwise a freshly-drawn random
+    /// field element — and returns it.
+    ///
+    /// Errors if every round has already been processed, if `g`'s degree
+    /// exceeds `self.poly`'s degree in the current variable, if `g(0) +
+    /// g(1)` doesn't match the running `expected` value, or if an
+    /// overridden `challenge` falls outside `[0, modulus)`.
+    pub fn process_round(&mut self, g: MultiVarPolynomial, challenge: Option<i32>) -> Result<i32, SumcheckError> {
+        let j = self.state.round;
+        if j >= self.poly.num_vars {
+            return Err(SumcheckError::UnsupportedOperation(
+                "every round has already been processed".to_string(),
+            ));
+        }
+
+        let expected_degree = self.poly.expected_round_degree(j);
+        let actual_degree = g.degree_in_var(0);
+        if actual_degree > expected_degree {
+            return Err(SumcheckError::DegreeCheckFailed {
+                round: j,
+                expected: expected_degree,
+                found: actual_degree,
+            });
+        }
+
+        let g_at_0 = g.evaluate(&[0])?;
+        let g_at_1 = g.evaluate(&[1])?;
+        if (g_at_0 + g_at_1).rem_euclid(self.poly.modulus) != self.state.expected {
+            return Err(SumcheckError::ConsistencyCheckFailed { round: j });
+        }
+
+        let r_j = match challenge {
+            Some(value) => {
+                if value < 0 || value >= self.poly.modulus {
+                    return Err(SumcheckError::InvalidChallenge { round: j, value });
+                }
+                value
+            }
+            None => {
+                let mut rng = rand::thread_rng();
+                random_field_element(self.poly.modulus, &mut rng)
+            }
+        };
+
+        self.state.expected = g.evaluate(&[r_j])?;
+        self.state.challenges.push(r_j);
+        self.state.round += 1;
+        Ok(r_j)
+    }
+
+    /// Performs the final oracle check: `r` must be the challenge the last
+    /// [`process_round`](Self::process_round) call returned, and
+    /// `g_last_eval` — the last round polynomial evaluated at `r` — must
+    /// match both the running `expected` value and `self.poly` evaluated
+    /// at every challenge sent so far.
+    ///
+    /// Errors if rounds remain unprocessed, if `r` doesn't match the last
+    /// recorded challenge, or if the final oracle check fails.
+    pub fn finalize(&self, r: i32, g_last_eval: i32) -> Result<(), SumcheckError> {
+        if self.state.round != self.poly.num_vars {
+            return Err(SumcheckError::UnsupportedOperation(
+                "finalize called before every round was processed".to_string(),
+            ));
+        }
+        if self.state.challenges.last() != Some(&r) {
+            return Err(SumcheckError::InvalidChallenge {
+                round: self.poly.num_vars.saturating_sub(1),
+                value: r,
+            });
+        }
+        if g_last_eval != self.state.expected {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+
+        let final_eval = self.poly.evaluate(&self.state.challenges)?;
+        if final_eval != g_last_eval {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+        Ok(())
+    }
+}
+
+/// Builds the multilinear extension of `evals`, computes its claimed sum
+/// over the boolean hypercube, and runs the honest prover against it,
+/// bundling the result into a [`SumcheckProof`] — so a caller with just a
+/// table of values gets a verifiable proof of their sum in one call.
+pub fn prove_hypercube_sum(evals: &[i32], modulus: i32) -> SumcheckProof {
+    prove_poly_sum(from_hypercube_evals(evals, modulus))
+}
+
+/// Runs the honest prover against `poly`'s boolean-hypercube sum and
+/// bundles the result into a [`SumcheckProof`], for any `poly` (not just
+/// the multilinear-from-evals case [`prove_hypercube_sum`] builds).
+fn prove_poly_sum(poly: MultiVarPolynomial) -> SumcheckProof {
+    let result = run_protocol_silent(poly.clone(), BTreeMap::new(), BTreeMap::new())
+        .expect("the honest prover always satisfies its own checks");
+    let challenges: Vec<i32> = result.round_stats.iter().map(|stat| stat.challenge).collect();
+    let round_polys: Vec<MultiVarPolynomial> = (0..poly.num_vars)
+        .map(|j| {
+            compute_g_j(&poly, j, &challenges[..j])
+                .expect("run_protocol_silent already computed this round successfully above")
+        })
+        .collect();
+    SumcheckProof {
+        num_vars: poly.num_vars,
+        modulus: poly.modulus,
+        claimed_sum: result.claimed_sum,
+        round_polys,
+        challenges,
+    }
+}
+
+/// Proves `∑_{x ∈ {0,1}^n} f(x) * g(x) = claimed` by forming the product
+/// polynomial `h = f * g` and running the honest prover against `h`'s
+/// boolean-hypercube sum. Returns `(claimed, proof)`; `proof`'s round
+/// polynomials are `h`'s, same as an ordinary sumcheck over `h` would
+/// produce, so [`correlated_sumcheck_verify`] replays them against `f` and
+/// `g` without ever needing `h` itself.
+///
+/// Errors if `f` and `g` don't share `num_vars` and `modulus`.
+pub fn correlated_sumcheck_prove(
+    f: &MultiVarPolynomial,
+    g: &MultiVarPolynomial,
+) -> Result<(i32, SumcheckProof), SumcheckError> {
+    if f.num_vars != g.num_vars {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: f.num_vars,
+            found: g.num_vars,
+        }));
+    }
+    if f.modulus != g.modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch {
+            left: f.modulus,
+            right: g.modulus,
+        }));
+    }
+
+    let h = f.clone() * g.clone();
+    let claimed = h.bool_sum();
+    Ok((claimed, prove_poly_sum(h)))
+}
+
+/// Verifies a [`correlated_sumcheck_prove`] proof that `∑ f(x) * g(x) =
+/// claimed` over the boolean hypercube, without ever materializing the
+/// product `h = f * g`: each round's degree bound is
+/// `f.expected_round_degree(j) + g.expected_round_degree(j)` (`h`'s degree
+/// in that variable, by how
+/// polynomial multiplication sums exponents), and the final oracle check
+/// evaluates `f` and `g` separately at the accumulated challenges and
+/// multiplies, rather than evaluating `h` directly.
+pub fn correlated_sumcheck_verify(
+    f: &MultiVarPolynomial,
+    g: &MultiVarPolynomial,
+    claimed: i32,
+    proof: &SumcheckProof,
+) -> bool {
+    if f.num_vars != g.num_vars || f.modulus != g.modulus || f.num_vars != proof.num_vars {
+        return false;
+    }
+    if proof.claimed_sum != claimed {
+        return false;
+    }
+
+    let modulus = f.modulus;
+    let mut expected = proof.claimed_sum;
+    for j in 0..proof.num_vars {
+        let g_j = &proof.round_polys[j];
+        let expected_degree = f.expected_round_degree(j) + g.expected_round_degree(j);
+        if g_j.degree_in_var(0) > expected_degree {
+            return false;
+        }
+        let Ok(g_j_at_0) = g_j.evaluate(&[0]) else { return false };
+        let Ok(g_j_at_1) = g_j.evaluate(&[1]) else { return false };
+        if (g_j_at_0 + g_j_at_1).rem_euclid(modulus) != expected {
+            return false;
+        }
+        let Ok(next_expected) = g_j.evaluate(&[proof.challenges[j]]) else { return false };
+        expected = next_expected;
+    }
+
+    let (Ok(f_r), Ok(g_r)) = (f.evaluate(&proof.challenges), g.evaluate(&proof.challenges)) else {
+        return false;
+    };
+    let product = ((f_r as i64 * g_r as i64).rem_euclid(modulus as i64)) as i32;
+    product == expected
+}
+
+/// Runs the Fiat-Shamir transform over [`compute_g_j`]: instead of a
+/// verifier drawing each round's challenge, a [`Transcript`] seeded with
+/// `domain_separator` derives it from the round polynomials sent so far,
+/// so the prover can produce the whole proof unilaterally and a verifier
+/// can replay the same derivation to check it wasn't tampered with.
+pub fn prove_non_interactive(poly: &MultiVarPolynomial, domain_separator: &str) -> SumcheckProof {
+    let claimed_sum = poly.bool_sum();
+    let mut transcript = Transcript::new(domain_separator, poly.num_vars, poly.modulus);
+    transcript.append_scalar(claimed_sum);
+
+    let mut challenges = Vec::with_capacity(poly.num_vars);
+    let mut round_polys = Vec::with_capacity(poly.num_vars);
+    for j in 0..poly.num_vars {
+        let g_j = compute_g_j(poly, j, &challenges).expect("num_vars within compute_g_j's practical bound");
+        transcript.append_poly(&g_j);
+        let r_j = transcript.challenge_scalar(poly.modulus);
+        round_polys.push(g_j);
+        challenges.push(r_j);
+    }
+
+    SumcheckProof {
+        num_vars: poly.num_vars,
+        modulus: poly.modulus,
+        claimed_sum,
+        round_polys,
+        challenges,
+    }
+}
+
+/// Verifies a [`prove_non_interactive`] proof against `polynomial`:
+/// re-derives every challenge from a fresh [`Transcript`] seeded with the
+/// same `domain_separator`, rejecting if any derived challenge doesn't
+/// match `proof.challenges` (which would mean the proof was built under a
+/// different domain separator, a different statement, or was tampered
+/// with), then delegates the degree, consistency, and final oracle checks
+/// to [`SumcheckProof::self_check`].
+pub fn verify_non_interactive(
+    polynomial: &MultiVarPolynomial,
+    domain_separator: &str,
+    proof: &SumcheckProof,
+) -> bool {
+    if proof.num_vars != polynomial.num_vars || proof.modulus != polynomial.modulus {
+        return false;
+    }
+
+    let mut transcript = Transcript::new(domain_separator, proof.num_vars, proof.modulus);
+    transcript.append_scalar(proof.claimed_sum);
+    for j in 0..proof.num_vars {
+        transcript.append_poly(&proof.round_polys[j]);
+        let r_j = transcript.challenge_scalar(proof.modulus);
+        if proof.challenges[j] != r_j {
+            return false;
+        }
+    }
+
+    proof.self_check(polynomial).is_ok()
+}
+
+/// The outcome of running [`soundness_experiment`]'s cheating-prover trials
+/// against a fresh verifier: how many of `trials` attempts slipped through
+/// despite claiming the wrong sum, the resulting empirical acceptance
+/// rate, and the theoretical Schwartz-Zippel bound it should stay under.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundnessReport {
+    pub trials: usize,
+    pub false_accepts: usize,
+    pub empirical_rate: f64,
+    pub theoretical_bound: f64,
+}
+
+/// Builds the cheating prover's round-0 message for [`soundness_experiment`]:
+/// the honest round-0 polynomial plus a "bump" of the form `k * (x - r_1) *
+/// ... * (x - r_d)`, chosen so the bump vanishes at every `root` in `roots`
+/// but `honest_g_0 + bump` sums to `wrong_sum` at `0` and `1` instead of
+/// `true_sum`. If the verifier's random challenge lands on one of `roots`,
+/// the bump vanishes there too, so the cheat is indistinguishable from an
+/// honest proof from that point on; otherwise it's caught at the very next
+/// round's consistency check.
+///
+/// Returns `None` if `roots` is empty, or if the bump's scale can't be
+/// solved for because its unscaled sum at `0` and `1` happens to vanish mod
+/// `modulus` — in both cases this particular construction can't mount the
+/// cheat, which [`soundness_experiment`] treats as zero false accepts
+/// rather than an error.
+fn build_cheating_round_zero(
+    honest_g_0: &MultiVarPolynomial,
+    wrong_sum: i32,
+    true_sum: i32,
+    roots: &[i32],
+    modulus: i32,
+) -> Option<MultiVarPolynomial> {
+    if roots.is_empty() {
+        return None;
+    }
+
+    let mut bump = MultiVarPolynomial::new(1, modulus);
+    bump.add_term(vec![0], 1);
+    for &root in roots {
+        let mut factor = MultiVarPolynomial::new(1, modulus);
+        factor.add_term(vec![1], 1);
+        factor.add_term(vec![0], (-root).rem_euclid(modulus));
+        bump *= factor;
+    }
+
+    let unscaled_sum = (bump.evaluate(&[0]).ok()? + bump.evaluate(&[1]).ok()?).rem_euclid(modulus);
+    let scale = mod_inverse(unscaled_sum, modulus)?;
+    let delta = (wrong_sum - true_sum).rem_euclid(modulus);
+    let k = ((delta as i64 * scale as i64).rem_euclid(modulus as i64)) as i32;
+    bump *= k;
+
+    Some(honest_g_0.clone() + bump)
+}
+
+/// Empirically measures how often a cheating prover claiming the wrong
+/// total `wrong_sum` (instead of `poly.bool_sum()`) slips past a fresh
+/// verifier over `num_trials` independent trials, as an automated sanity
+/// check of the protocol's soundness alongside its proof.
+///
+/// The cheat: the round-0 message is the honest one plus a low-degree bump
+/// (see [`build_cheating_round_zero`]) chosen to vanish at `degree_in_var(0)`
+/// distinct points, making `g_0(0) + g_0(1) = wrong_sum` check out. If the
+/// verifier's random round-0 challenge lands on one of those points, the
+/// cheat is undetectable from then on and an honest continuation of the
+/// protocol accepts; otherwise the very next round's consistency check
+/// catches it. So acceptance for a trial reduces to a single coin flip:
+/// whether that one random challenge hit one of the bump's roots.
+///
+/// `theoretical_bound` is the standard Schwartz-Zippel union bound over
+/// every round, `total_degree * num_vars / modulus` — looser than this
+/// particular single-round cheat's true ceiling of `degree_in_var(0) /
+/// modulus`, but the bound soundness actually guarantees against *any*
+/// cheating strategy, not just this one.
+pub fn soundness_experiment(
+    poly: &MultiVarPolynomial,
+    wrong_sum: i32,
+    num_trials: usize,
+    rng: &mut impl Rng,
+) -> SoundnessReport {
+    let modulus = poly.modulus;
+    let total_degree: usize = (0..poly.num_vars).map(|j| poly.degree_in_var(j)).sum();
+    let theoretical_bound = (total_degree * poly.num_vars) as f64 / modulus as f64;
+
+    let true_sum = poly.bool_sum();
+    let honest_g_0 = compute_g_j(poly, 0, &[]).expect("num_vars within compute_g_j's practical bound");
+    let roots: Vec<i32> = (2..modulus).take(poly.degree_in_var(0)).collect();
+    let cheat_possible = true_sum.rem_euclid(modulus) != wrong_sum.rem_euclid(modulus)
+        && build_cheating_round_zero(&honest_g_0, wrong_sum, true_sum, &roots, modulus).is_some();
+
+    let mut false_accepts = 0;
+    for _ in 0..num_trials {
+        let challenge = random_field_element(modulus, rng);
+        if cheat_possible && roots.contains(&challenge) {
+            false_accepts += 1;
+        }
+    }
+
+    let empirical_rate = if num_trials == 0 { 0.0 } else { false_accepts as f64 / num_trials as f64 };
+    SoundnessReport {
+        trials: num_trials,
+        false_accepts,
+        empirical_rate,
+        theoretical_bound,
+    }
+}
+
+/// A proof that `∏ values[i] = claimed_product`, reduced to a sumcheck via
+/// the log-derivative trick: for a random challenge `z`, `claimed_product`
+/// is tied to `aux_poly`, the multilinear extension of `1/(z - values[i])`
+/// for every `i`, via `values[i] = z - (aux_poly(i))^-1`. [`grand_product_verify`]
+/// re-derives each `values[i]` from `aux_poly` this way and recomputes the
+/// product directly, so soundness rests on [`SumcheckProof::self_check`]
+/// actually binding `sumcheck_proof` to `aux_poly` — a cheating prover
+/// cannot swap in an inconsistent `aux_poly` without the embedded sumcheck
+/// proof failing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrandProductProof {
+    pub claimed_product: i32,
+    pub challenge: i32,
+    pub sumcheck_proof: SumcheckProof,
+    pub aux_poly: MultiVarPolynomial,
+}
+
+/// Proves `∏_{i=0}^{n-1} values[i] = claimed_product (mod p)` using the
+/// log-derivative trick: samples a random challenge `z` with `rng`, forms
+/// `aux_poly`, the multilinear extension of `1/(z - values[i])`, and proves
+/// its hypercube sum via [`prove_hypercube_sum`]. `values.len()` must be a
+/// power of two, matching [`from_hypercube_evals`]'s requirement.
+pub fn grand_product_prove(
+    values: &[i32],
+    modulus: i32,
+    rng: &mut impl Rng,
+) -> Result<GrandProductProof, SumcheckError> {
+    if values.is_empty() || !values.len().is_power_of_two() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "grand_product_prove requires a non-empty, power-of-two number of values".to_string(),
+        ));
+    }
+
+    let claimed_product = values
+        .iter()
+        .fold(1i32, |acc, &v| ((acc as i64 * v as i64).rem_euclid(modulus as i64)) as i32);
+
+    let challenge = loop {
+        let z = random_field_element(modulus, rng);
+        if values.iter().all(|&v| (z - v).rem_euclid(modulus) != 0) {
+            break z;
+        }
+    };
+
+    let inverses: Vec<i32> = values
+        .iter()
+        .map(|&v| {
+            mod_inverse((challenge - v).rem_euclid(modulus), modulus)
+                .expect("challenge was chosen so that challenge - v is never 0 mod p")
+        })
+        .collect();
+    let aux_poly = from_hypercube_evals(&inverses, modulus);
+    let sumcheck_proof = prove_hypercube_sum(&inverses, modulus);
+
+    Ok(GrandProductProof {
+        claimed_product,
+        challenge,
+        sumcheck_proof,
+        aux_poly,
+    })
+}
+
+/// Verifies a [`GrandProductProof`] against an independently-supplied
+/// `claimed` product: checks `proof.claimed_product == claimed`, replays
+/// `proof.sumcheck_proof` against `proof.aux_poly` via
+/// [`SumcheckProof::self_check`], then re-derives each original value as
+/// `challenge - aux_poly(i)^-1` from `proof.aux_poly`'s hypercube
+/// evaluations and confirms their product matches.
+pub fn grand_product_verify(proof: &GrandProductProof, claimed: i32, modulus: i32) -> bool {
+    if proof.claimed_product != claimed.rem_euclid(modulus) {
+        return false;
+    }
+    if proof.sumcheck_proof.self_check(&proof.aux_poly).is_err() {
+        return false;
+    }
+
+    let Ok(inverses) = eval_hypercube(&proof.aux_poly) else {
+        return false;
+    };
+    let mut product = 1i32;
+    for inv in inverses {
+        let Some(challenge_minus_v) = mod_inverse(inv, modulus) else {
+            return false;
+        };
+        let v = (proof.challenge - challenge_minus_v).rem_euclid(modulus);
+        product = ((product as i64 * v as i64).rem_euclid(modulus as i64)) as i32;
+    }
+    product == proof.claimed_product
+}
+
+/// A proof that `g` is a permutation of `f`, via the fingerprint polynomial
+/// approach: for a random challenge `r`, `∏(r - f[i]) = ∏(r - g[i])` holds
+/// with overwhelming probability iff the multisets `f` and `g` are equal.
+/// Each side's product is itself proved with a [`GrandProductProof`], so
+/// [`verify_permutation`] need not trust either product directly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermutationProof {
+    pub challenge: i32,
+    pub left_grand_product: GrandProductProof,
+    pub right_grand_product: GrandProductProof,
+}
+
+/// Proves that `g` is a permutation of `f`: samples a random challenge `r`,
+/// then proves `∏(r - f[i]) = ∏(r - g[i])` as two independent
+/// [`GrandProductProof`]s, one over `r - f[i]` and one over `r - g[i]`.
+/// `f` and `g` must have the same length, and that length must be a
+/// power of two to satisfy [`grand_product_prove`]'s requirement.
+///
+/// This is the fingerprinting step behind PLONK-style copy constraints,
+/// which check that values shared between separate wires of a circuit are
+/// really the same values, just permuted.
+pub fn prove_permutation(
+    f: &[i32],
+    g: &[i32],
+    modulus: i32,
+    rng: &mut impl Rng,
+) -> Result<PermutationProof, SumcheckError> {
+    if f.len() != g.len() {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: f.len(),
+            found: g.len(),
+        }));
+    }
+
+    let challenge = loop {
+        let r = random_field_element(modulus, rng);
+        if f.iter().chain(g.iter()).all(|&v| (r - v).rem_euclid(modulus) != 0) {
+            break r;
+        }
+    };
+
+    let left_values: Vec<i32> = f.iter().map(|&v| (challenge - v).rem_euclid(modulus)).collect();
+    let right_values: Vec<i32> = g.iter().map(|&v| (challenge - v).rem_euclid(modulus)).collect();
+    let left_grand_product = grand_product_prove(&left_values, modulus, rng)?;
+    let right_grand_product = grand_product_prove(&right_values, modulus, rng)?;
+
+    Ok(PermutationProof {
+        challenge,
+        left_grand_product,
+        right_grand_product,
+    })
+}
+
+/// Verifies a [`PermutationProof`]: re-derives `r - f[i]` and `r - g[i]`
+/// from `proof.challenge`, confirms each side's [`GrandProductProof`]
+/// actually proves the product of those values, and then checks the two
+/// proved products are equal — the fingerprint check that `f` and `g` are
+/// permutations of each other.
+pub fn verify_permutation(proof: &PermutationProof, f: &[i32], g: &[i32], modulus: i32) -> bool {
+    if f.len() != g.len() {
+        return false;
+    }
+
+    let left_claimed = f
+        .iter()
+        .map(|&v| (proof.challenge - v).rem_euclid(modulus))
+        .fold(1i32, |acc, v| ((acc as i64 * v as i64).rem_euclid(modulus as i64)) as i32);
+    let right_claimed = g
+        .iter()
+        .map(|&v| (proof.challenge - v).rem_euclid(modulus))
+        .fold(1i32, |acc, v| ((acc as i64 * v as i64).rem_euclid(modulus as i64)) as i32);
+
+    if !grand_product_verify(&proof.left_grand_product, left_claimed, modulus) {
+        return false;
+    }
+    if !grand_product_verify(&proof.right_grand_product, right_claimed, modulus) {
+        return false;
+    }
+    proof.left_grand_product.claimed_product == proof.right_grand_product.claimed_product
+}
+
+/// A PLONK-style arithmetic gate: the selector polynomials `q_l`, `q_r`,
+/// `q_o`, `q_m`, `q_c` that, together with a wire assignment `(a, b, c)`,
+/// encode the gate equation `q_l*a + q_r*b + q_o*c + q_m*a*b + q_c = 0`.
+/// Setting the appropriate selectors to 0 or 1 specializes this to a plain
+/// addition gate (`q_l = q_r = 1, q_o = -1, q_m = q_c = 0`), a
+/// multiplication gate (`q_o = -1, q_m = 1`, the rest 0), or a constant
+/// gate (`q_c` carries the constant, the rest 0).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlonkGateConstraint {
+    pub q_l: MultiVarPolynomial,
+    pub q_r: MultiVarPolynomial,
+    pub q_o: MultiVarPolynomial,
+    pub q_m: MultiVarPolynomial,
+    pub q_c: MultiVarPolynomial,
+}
+
+impl PlonkGateConstraint {
+    /// Evaluates the gate equation `q_l*a + q_r*b + q_o*c + q_m*a*b + q_c` at
+    /// `point`: builds the combined constraint polynomial via
+    /// [`MultiVarPolynomial`]'s `Add`/`Mul` impls, then evaluates it. An
+    /// honest wire assignment makes this 0 at every boolean point;
+    /// [`PlonkGateConstraint::is_satisfied`] checks exactly that.
+    pub fn evaluate_constraint(
+        &self,
+        a: &MultiVarPolynomial,
+        b: &MultiVarPolynomial,
+        c: &MultiVarPolynomial,
+        point: &[i32],
+    ) -> i32 {
+        let combined = self.q_l.clone() * a.clone()
+            + self.q_r.clone() * b.clone()
+            + self.q_o.clone() * c.clone()
+            + self.q_m.clone() * a.clone() * b.clone()
+            + self.q_c.clone();
+        combined.evaluate(point).expect("point matches the gate's num_vars")
+    }
+
+    /// Checks that [`PlonkGateConstraint::evaluate_constraint`] is 0 at
+    /// every point of the boolean hypercube, i.e. the gate holds for every
+    /// row of the circuit's execution trace.
+    pub fn is_satisfied(&self, a: &MultiVarPolynomial, b: &MultiVarPolynomial, c: &MultiVarPolynomial) -> bool {
+        MultiVarPolynomial::hypercube_iter(self.q_l.num_vars)
+            .all(|point| self.evaluate_constraint(a, b, c, &point) == 0)
+    }
+}
+
+/// A Haböck-style logUp lookup proof that every value in `witness` appears
+/// in `table`: the multiplicities `m_j` of each table entry in the witness,
+/// and a sumcheck proof that `∑_j m_j / (challenge - table[j])` equals the
+/// claimed sum. [`logup_verify`] ties this back to the witness by
+/// independently computing `∑_i 1/(challenge - witness[i])` and checking it
+/// against the same claimed sum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogUpProof {
+    pub challenge: i32,
+    pub multiplicity_poly: MultiVarPolynomial,
+    pub sumcheck_proof: SumcheckProof,
+}
+
+/// Proves that every value in `witness` appears in `table`, via the
+/// log-derivative identity `∑_i 1/(z - witness[i]) = ∑_j m_j/(z - table[j])`,
+/// where `m_j` counts how often `table[j]` occurs in `witness`. `table`
+/// entries must be distinct, and `table.len()` must be a power of two to
+/// satisfy [`from_hypercube_evals`]'s requirement for `multiplicity_poly`.
+///
+/// Errors if `table` is empty or not a power-of-two length, or if any
+/// witness value does not occur in `table`.
+pub fn logup_prove(
+    table: &[i32],
+    witness: &[i32],
+    modulus: i32,
+    rng: &mut impl Rng,
+) -> Result<LogUpProof, SumcheckError> {
+    if table.is_empty() || !table.len().is_power_of_two() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "logup_prove requires a non-empty, power-of-two table size".to_string(),
+        ));
+    }
+    for &w in witness {
+        if !table.contains(&w) {
+            return Err(SumcheckError::UnsupportedOperation(
+                "logup_prove requires every witness value to occur in the table".to_string(),
+            ));
+        }
+    }
+
+    let challenge = loop {
+        let z = random_field_element(modulus, rng);
+        if table.iter().all(|&t| (z - t).rem_euclid(modulus) != 0) {
+            break z;
+        }
+    };
+
+    let multiplicities: Vec<i32> = table
+        .iter()
+        .map(|&t| witness.iter().filter(|&&w| w == t).count() as i32)
+        .collect();
+    let contributions: Vec<i32> = table
+        .iter()
+        .zip(multiplicities.iter())
+        .map(|(&t, &m)| {
+            let inv = mod_inverse((challenge - t).rem_euclid(modulus), modulus)
+                .expect("challenge was chosen so that challenge - t is never 0 mod p");
+            ((m as i64 * inv as i64).rem_euclid(modulus as i64)) as i32
+        })
+        .collect();
+
+    let multiplicity_poly = from_hypercube_evals(&multiplicities, modulus);
+    let sumcheck_proof = prove_hypercube_sum(&contributions, modulus);
+
+    Ok(LogUpProof {
+        challenge,
+        multiplicity_poly,
+        sumcheck_proof,
+    })
+}
+
+/// Verifies a [`LogUpProof`] against `table` and `witness`: recomputes the
+/// per-table-entry contribution `m_j / (challenge - table[j])` from
+/// `proof.multiplicity_poly`'s hypercube evaluations, replays
+/// `proof.sumcheck_proof` against that contribution polynomial via
+/// [`SumcheckProof::self_check`], and checks the proven sum against the
+/// witness side `∑_i 1/(challenge - witness[i])` computed directly from
+/// `witness`.
+pub fn logup_verify(proof: &LogUpProof, table: &[i32], witness: &[i32], modulus: i32) -> bool {
+    let Ok(multiplicities) = eval_hypercube(&proof.multiplicity_poly) else {
+        return false;
+    };
+    if table.len() != multiplicities.len() {
+        return false;
+    }
+
+    let mut contributions = Vec::with_capacity(table.len());
+    for (&t, &m) in table.iter().zip(multiplicities.iter()) {
+        let Some(inv) = mod_inverse((proof.challenge - t).rem_euclid(modulus), modulus) else {
+            return false;
+        };
+        contributions.push(((m as i64 * inv as i64).rem_euclid(modulus as i64)) as i32);
+    }
+    let contribution_poly = from_hypercube_evals(&contributions, modulus);
+    if proof.sumcheck_proof.self_check(&contribution_poly).is_err() {
+        return false;
+    }
+
+    let mut witness_sum = 0i32;
+    for &w in witness {
+        let Some(inv) = mod_inverse((proof.challenge - w).rem_euclid(modulus), modulus) else {
+            return false;
+        };
+        witness_sum = ((witness_sum as i64 + inv as i64).rem_euclid(modulus as i64)) as i32;
+    }
+
+    witness_sum == proof.sumcheck_proof.claimed_sum
+}
+
+/// A placeholder inner product argument, establishing the API this crate
+/// will eventually expose once the generators in `g_vec` become actual
+/// group elements rather than field elements standing in for them. The
+/// recursive halving argument it implements is real -- [`prove_inner_product`]
+/// and [`verify_inner_product`] fold `a`, `b`, and `g_vec` to a single
+/// element in `log2(a.len())` rounds -- but without real group elements
+/// there is no hiding or binding, so this is not yet a usable commitment
+/// scheme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InnerProductArgument {
+    pub g_vec: Vec<i32>,
+    pub modulus: i32,
+}
+
+/// One round of folding: the cross terms for the `a`/`b` inner product and
+/// the `a`/`g_vec` commitment, and the challenge the verifier sent to fold
+/// them. [`verify_inner_product`] replays the same folding on `commitment`
+/// and `claimed` without ever seeing `a` or `b` in full.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IPARound {
+    pub l_inner: i32,
+    pub r_inner: i32,
+    pub l_commitment: i32,
+    pub r_commitment: i32,
+    pub challenge: i32,
+}
+
+/// A recursive halving argument that `<final_a, final_b> = claimed` and
+/// `<final_a, final_g> = commitment` once every round in `rounds` has been
+/// folded away. `modulus` travels with the proof since [`verify_inner_product`]
+/// takes no modulus of its own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IPAProof {
+    pub rounds: Vec<IPARound>,
+    pub final_a: i32,
+    pub final_b: i32,
+    pub final_g: i32,
+    pub modulus: i32,
+}
+
+/// Commits to `poly_coeffs` against `ipa.g_vec` as their dot product mod
+/// `ipa.modulus`. `poly_coeffs` must have the same length as `ipa.g_vec`.
+pub fn commit(ipa: &InnerProductArgument, poly_coeffs: &[i32]) -> i32 {
+    assert_eq!(
+        poly_coeffs.len(),
+        ipa.g_vec.len(),
+        "poly_coeffs must have the same length as ipa.g_vec"
+    );
+    dot_product(poly_coeffs, &ipa.g_vec, ipa.modulus)
+}
+
+fn dot_product(a: &[i32], b: &[i32], modulus: i32) -> i32 {
+    a.iter()
+        .zip(b.iter())
+        .fold(0i32, |acc, (&x, &y)| {
+            ((acc as i64 + x as i64 * y as i64).rem_euclid(modulus as i64)) as i32
+        })
+}
+
+/// Proves `<a, b> = claimed` for `claimed = dot_product(a, b, ipa.modulus)`,
+/// tying the proof to `commit(ipa, a)` by folding `ipa.g_vec` alongside `a`
+/// and `b` at every round. `a`, `b`, and `ipa.g_vec` must all have the same
+/// power-of-two length.
+///
+/// Each round splits the current `a`, `b`, `g_vec` in half, records the
+/// cross terms `<a_L, b_R>`/`<a_R, b_L>` and `<a_L, g_R>`/`<a_R, g_L>`, then
+/// folds `a' = a_L + x * a_R`, `b' = b_L + x^-1 * b_R`, and
+/// `g' = g_L + x^-1 * g_R` under a random challenge `x`. This keeps
+/// `<a', b'>` and `<a', g'>` recoverable from the previous round's value
+/// plus the recorded cross terms, which is exactly what
+/// [`verify_inner_product`] replays without ever seeing `a` or `b`.
+pub fn prove_inner_product(
+    ipa: &InnerProductArgument,
+    a: &[i32],
+    b: &[i32],
+    rng: &mut impl Rng,
+) -> Result<IPAProof, SumcheckError> {
+    if a.len() != b.len() || a.len() != ipa.g_vec.len() {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: ipa.g_vec.len(),
+            found: a.len(),
+        }));
+    }
+    if a.is_empty() || !a.len().is_power_of_two() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "prove_inner_product requires a non-empty, power-of-two length".to_string(),
+        ));
+    }
+
+    let modulus = ipa.modulus;
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    let mut g = ipa.g_vec.clone();
+    let mut rounds = Vec::with_capacity(a.len().trailing_zeros() as usize);
+
+    while a.len() > 1 {
+        let half = a.len() / 2;
+        let (a_l, a_r) = (&a[..half], &a[half..]);
+        let (b_l, b_r) = (&b[..half], &b[half..]);
+        let (g_l, g_r) = (&g[..half], &g[half..]);
+
+        let l_inner = dot_product(a_l, b_r, modulus);
+        let r_inner = dot_product(a_r, b_l, modulus);
+        let l_commitment = dot_product(a_l, g_r, modulus);
+        let r_commitment = dot_product(a_r, g_l, modulus);
+
+        let challenge = loop {
+            let x = random_field_element(modulus, rng);
+            if x != 0 {
+                break x;
+            }
+        };
+        let challenge_inv = mod_inverse(challenge, modulus)
+            .expect("challenge is non-zero and modulus is prime, so it has an inverse");
+
+        a = a_l
+            .iter()
+            .zip(a_r.iter())
+            .map(|(&l, &r)| ((l as i64 + challenge as i64 * r as i64).rem_euclid(modulus as i64)) as i32)
+            .collect();
+        b = b_l
+            .iter()
+            .zip(b_r.iter())
+            .map(|(&l, &r)| ((l as i64 + challenge_inv as i64 * r as i64).rem_euclid(modulus as i64)) as i32)
+            .collect();
+        g = g_l
+            .iter()
+            .zip(g_r.iter())
+            .map(|(&l, &r)| ((l as i64 + challenge_inv as i64 * r as i64).rem_euclid(modulus as i64)) as i32)
+            .collect();
+
+        rounds.push(IPARound {
+            l_inner,
+            r_inner,
+            l_commitment,
+            r_commitment,
+            challenge,
+        });
+    }
+
+    Ok(IPAProof {
+        rounds,
+        final_a: a[0],
+        final_b: b[0],
+        final_g: g[0],
+        modulus,
+    })
+}
+
+/// Verifies an [`IPAProof`] against an independently-supplied `commitment`
+/// and `claimed` inner product: folds both down round by round using the
+/// recorded cross terms and challenges, then checks that the final folded
+/// values satisfy `final_a * final_b == claimed` and
+/// `final_a * final_g == commitment`.
+pub fn verify_inner_product(proof: &IPAProof, commitment: i32, claimed: i32) -> bool {
+    let modulus = proof.modulus;
+    let mut claimed = claimed.rem_euclid(modulus);
+    let mut commitment = commitment.rem_euclid(modulus);
+
+    for round in &proof.rounds {
+        let Some(challenge_inv) = mod_inverse(round.challenge.rem_euclid(modulus), modulus) else {
+            return false;
+        };
+        claimed = ((claimed as i64
+            + round.challenge as i64 * round.r_inner as i64
+            + challenge_inv as i64 * round.l_inner as i64)
+            .rem_euclid(modulus as i64)) as i32;
+        commitment = ((commitment as i64
+            + round.challenge as i64 * round.r_commitment as i64
+            + challenge_inv as i64 * round.l_commitment as i64)
+            .rem_euclid(modulus as i64)) as i32;
+    }
+
+    let final_inner = ((proof.final_a as i64 * proof.final_b as i64).rem_euclid(modulus as i64)) as i32;
+    let final_commitment = ((proof.final_a as i64 * proof.final_g as i64).rem_euclid(modulus as i64)) as i32;
+    final_inner == claimed && final_commitment == commitment
+}
+
+/// Runs the interactive sumcheck protocol for `polynomial`, emitting a
+/// transcript of each round. With the `tracing` feature enabled, the
+/// transcript is emitted as structured `tracing` events that any installed
+/// `tracing_subscriber` can format and filter; otherwise it is printed to
+/// stdout. Thin wrapper around [`run_protocol_silent`], which does the
+/// actual work.
+///
+/// Requires the `std` feature, since its non-`tracing` path prints to
+/// stdout; `no_std` callers should use [`run_protocol_silent`] or
+/// [`run_protocol_with_callback`] directly.
+#[cfg(feature = "std")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip(polynomial, prover_overrides), fields(num_vars = polynomial.num_vars, modulus = polynomial.modulus))
+)]
+pub fn run_protocol(
+    polynomial: MultiVarPolynomial,
+    prover_overrides: BTreeMap<usize, MultiVarPolynomial>,
+    verifier_overrides: BTreeMap<usize, i32>,
+) -> Result<bool, SumcheckError> {
+    let result = run_protocol_silent(polynomial, prover_overrides, verifier_overrides)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(claimed_sum = result.claimed_sum, "prover claimed sum");
+    #[cfg(not(feature = "tracing"))]
+    println!("Prover claims sum C = {}", result.claimed_sum);
+
+    for stat in &result.round_stats {
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            round = stat.round,
+            degree_ok = stat.degree_ok,
+            consistency_check = stat.consistency_ok,
+            challenge = stat.challenge,
+            "round completed"
+        );
+        #[cfg(not(feature = "tracing"))]
+        println!(
+            "Round {}: degree_ok = {}, consistency_ok = {}, verifier sends challenge r_{} = {}",
+            stat.round, stat.degree_ok, stat.consistency_ok, stat.round, stat.challenge
+        );
+    }
+
+    if result.accepted {
+        #[cfg(feature = "tracing")]
+        tracing::info!("verifier accepted the proof");
+        #[cfg(not(feature = "tracing"))]
+        println!("Verifier accepts the proof.");
+    }
+    Ok(result.accepted)
+}
+
+/// Runs the protocol like [`run_protocol_silent`], but invokes `on_round`
+/// with each [`RoundStat`] as it completes. Lets callers build their own
+/// transcript (logging, progress bars, test assertions, ...) without the
+/// crate dictating an output format or forcing stdout.
+pub fn run_protocol_with_callback(
+    polynomial: MultiVarPolynomial,
+    prover_overrides: BTreeMap<usize, MultiVarPolynomial>,
+    verifier_overrides: BTreeMap<usize, i32>,
+    mut on_round: impl FnMut(&RoundStat),
+) -> Result<ProtocolResult, SumcheckError> {
+    let result = run_protocol_silent(polynomial, prover_overrides, verifier_overrides)?;
+    for stat in &result.round_stats {
+        on_round(stat);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Given an honest [`SumcheckProof`], produces a family of minimally
+    /// perturbed cheating proofs — one coefficient flipped, one round's
+    /// degree bumped past its bound, and one challenge altered — so tests
+    /// can assert [`SumcheckProof::self_check`] rejects every one of them.
+    fn gen_cheating_proofs(honest: &SumcheckProof) -> Vec<SumcheckProof> {
+        let mut cheats = Vec::new();
+
+        if let Some(g_0) = honest.round_polys.first() {
+            let mut cheat = honest.clone();
+            cheat.round_polys[0].add_term(vec![0], 1);
+            cheats.push(cheat);
+
+            let mut cheat = honest.clone();
+            let bumped_degree = g_0.degree_in_var(0) + 1;
+            cheat.round_polys[0].add_term(vec![bumped_degree], 1);
+            cheats.push(cheat);
+        }
+
+        if let Some(&first_challenge) = honest.challenges.first() {
+            let mut cheat = honest.clone();
+            cheat.challenges[0] = (first_challenge + 1).rem_euclid(cheat.modulus);
+            cheats.push(cheat);
+        }
+
+        cheats
+    }
+
+    #[test]
+    fn test_gen_cheating_proofs_are_all_rejected_by_self_check() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let honest = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+        assert_eq!(honest.self_check(&poly), Ok(()));
+
+        let cheats = gen_cheating_proofs(&honest);
+        assert!(!cheats.is_empty());
+        for cheat in cheats {
+            assert!(cheat.self_check(&poly).is_err());
+        }
+    }
+
+    #[test]
+    fn test_add_term() {
+        let mut poly = MultiVarPolynomial::new(2, 5);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![1, 0], 2);
+        // (3 + 2) % 5 == 0, so the term cancels out.
+        assert_eq!(poly.terms.get(&vec![1, 0]), Some(&0));
+    }
+
+    #[test]
+    fn test_is_reduced_true_for_a_polynomial_built_through_add_term() {
+        let mut poly = MultiVarPolynomial::new(2, 5);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 4);
+        assert!(poly.is_reduced());
+    }
+
+    #[test]
+    fn test_is_reduced_false_for_directly_mutated_terms() {
+        let mut poly = MultiVarPolynomial::new(2, 5);
+        poly.add_term(vec![1, 0], 3);
+        // Mutate `terms` directly, bypassing `add_term`'s reduction.
+        poly.terms.insert(vec![0, 1], 12);
+        assert!(!poly.is_reduced());
+
+        let mut with_zero = MultiVarPolynomial::new(1, 5);
+        with_zero.terms.insert(vec![0], 0);
+        assert!(!with_zero.is_reduced());
+    }
+
+    #[test]
+    fn test_reduce_fixes_unreduced_and_negative_coefficients() {
+        let mut poly = MultiVarPolynomial::new(2, 5);
+        poly.terms.insert(vec![1, 0], 12);
+        poly.terms.insert(vec![0, 1], -2);
+        poly.terms.insert(vec![0, 0], 5);
+        assert!(!poly.is_reduced());
+
+        poly.reduce();
+        assert!(poly.is_reduced());
+        assert_eq!(poly.terms.get(&vec![1, 0]), Some(&2));
+        assert_eq!(poly.terms.get(&vec![0, 1]), Some(&3));
+        // 5 mod 5 == 0, so the constant term is dropped entirely.
+        assert_eq!(poly.terms.get(&vec![0, 0]), None);
+    }
+
+    #[test]
+    fn test_coefficient_of_a_present_absent_and_zeroed_monomial() {
+        let mut poly = MultiVarPolynomial::new(2, 5);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![1, 1], 3);
+        poly.add_term(vec![1, 1], 2);
+
+        // Present.
+        assert_eq!(poly.coefficient(&[1, 0]), Ok(3));
+        // Absent: never added.
+        assert_eq!(poly.coefficient(&[0, 1]), Ok(0));
+        // Zeroed: (3 + 2) % 5 == 0, still present in `terms` but coefficient 0.
+        assert_eq!(poly.coefficient(&[1, 1]), Ok(0));
+        assert_eq!(poly.terms.get(&vec![1, 1]), Some(&0));
+    }
+
+    #[test]
+    fn test_coefficient_rejects_a_wrong_length_exponent_vector() {
+        let poly = MultiVarPolynomial::new(2, 5);
+        assert_eq!(
+            poly.coefficient(&[0, 0, 0]),
+            Err(PolyError::DimensionMismatch { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn test_coefficient_fe_matches_coefficient_as_a_field_element() {
+        let mut poly = MultiVarPolynomial::new(2, 5);
+        poly.add_term(vec![1, 0], 3);
+
+        assert_eq!(poly.coefficient_fe(&[1, 0]), Ok(FieldElement::new(3, 5)));
+        assert_eq!(poly.coefficient_fe(&[0, 1]), Ok(FieldElement::new(0, 5)));
+    }
+
+    #[test]
+    fn test_add_term_fe_matches_add_term_with_the_field_elements_value() {
+        let modulus = 13;
+        let mut via_fe = MultiVarPolynomial::new(2, modulus);
+        via_fe.add_term_fe(vec![1, 1], FieldElement::new(9, modulus));
+
+        let mut via_i32 = MultiVarPolynomial::new(2, modulus);
+        via_i32.add_term(vec![1, 1], 9);
+
+        assert_eq!(via_fe, via_i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus mismatch")]
+    fn test_add_term_fe_rejects_a_mismatched_modulus() {
+        let mut poly = MultiVarPolynomial::new(1, 13);
+        poly.add_term_fe(vec![0], FieldElement::new(1, 5));
+    }
+
+    #[test]
+    fn test_constant_term_matches_eval_at_the_origin_when_present() {
+        let mut poly = MultiVarPolynomial::new(2, 13);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 0], 7);
+
+        assert_eq!(poly.constant_term(), 7);
+        assert_eq!(poly.constant_term(), poly.evaluate(&vec![0; poly.num_vars]).unwrap());
+    }
+
+    #[test]
+    fn test_constant_term_is_zero_when_absent() {
+        let mut poly = MultiVarPolynomial::new(2, 13);
+        poly.add_term(vec![1, 0], 3);
+
+        assert_eq!(poly.constant_term(), 0);
+        assert_eq!(poly.constant_term(), poly.evaluate(&vec![0; poly.num_vars]).unwrap());
+    }
+
+    #[test]
+    fn test_new_ring_skips_the_primality_check() {
+        let poly = MultiVarPolynomial::new_ring(2, 12);
+        assert!(!poly.is_field());
+        assert_eq!(poly.modulus, 12);
+    }
+
+    #[test]
+    fn test_is_field_true_for_a_prime_modulus() {
+        let poly = MultiVarPolynomial::new(2, 13);
+        assert!(poly.is_field());
+    }
+
+    #[test]
+    fn test_ring_polynomial_still_supports_arithmetic_and_evaluation() {
+        let mut f = MultiVarPolynomial::new_ring(2, 12);
+        f.add_term(vec![1, 0], 5);
+        f.add_term(vec![0, 1], 7);
+        let g = f.clone() + f.clone();
+        assert_eq!(g.evaluate(&[2, 3]).unwrap(), ((5 * 2 + 7 * 3) * 2i32).rem_euclid(12));
+    }
+
+    #[test]
+    fn test_poly_eq_probabilistic_equal() {
+        let mut f = MultiVarPolynomial::new(2, 10007);
+        f.add_term(vec![1, 1], 5);
+        f.add_term(vec![0, 0], 7);
+        let g = f.clone();
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            assert!(poly_eq_probabilistic(&f, &g, &mut rng));
+        }
+    }
+
+    #[test]
+    fn test_poly_eq_probabilistic_unequal() {
+        let modulus = 10007;
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 1], 5);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![1, 1], 6);
+
+        let mut rng = rand::thread_rng();
+        let mismatches = (0..20)
+            .filter(|_| !poly_eq_probabilistic(&f, &g, &mut rng))
+            .count();
+        assert!(mismatches > 0);
+    }
+
+    #[test]
+    fn test_poly_eq_probabilistic_shape_mismatch() {
+        let mut rng = rand::thread_rng();
+        let f = MultiVarPolynomial::new(2, 7);
+        let g = MultiVarPolynomial::new(3, 7);
+        assert!(!poly_eq_probabilistic(&f, &g, &mut rng));
+    }
+
+    #[test]
+    fn test_poly_fingerprint_agrees_when_seeded_with_the_same_point() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut f = MultiVarPolynomial::new(2, 10007);
+        f.add_term(vec![1, 1], 5);
+        f.add_term(vec![0, 0], 7);
+        let g = f.clone();
+
+        // Two identically-seeded RNGs draw the same point, so equal
+        // polynomials fingerprint to the same value.
+        let fingerprint_f = poly_fingerprint(&f, &mut StdRng::seed_from_u64(42));
+        let fingerprint_g = poly_fingerprint(&g, &mut StdRng::seed_from_u64(42));
+        assert_eq!(fingerprint_f, fingerprint_g);
+    }
+
+    #[test]
+    fn test_poly_fingerprints_match_for_equal_polynomials() {
+        let mut f = MultiVarPolynomial::new(2, 10007);
+        f.add_term(vec![1, 1], 5);
+        f.add_term(vec![0, 0], 7);
+        let g = f.clone();
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            assert!(poly_fingerprints_match(&f, &g, &mut rng));
+        }
+    }
+
+    #[test]
+    fn test_poly_fingerprints_match_detects_unequal_polynomials() {
+        let modulus = 10007;
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 1], 5);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![1, 1], 6);
+
+        let mut rng = rand::thread_rng();
+        let mismatches = (0..20)
+            .filter(|_| !poly_fingerprints_match(&f, &g, &mut rng))
+            .count();
+        assert!(mismatches > 0);
+    }
+
+    #[test]
+    fn test_map_coefficients_doubles_every_coefficient() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 10);
+
+        let doubled = map_coefficients(&poly, |c| c * 2);
+        assert_eq!(doubled.terms.get(&vec![1, 0]), Some(&6));
+        assert_eq!(doubled.terms.get(&vec![0, 1]), Some(&7)); // (10*2) % 13 == 7
+    }
+
+    #[test]
+    fn test_fold_coefficients_computes_l2_norm_squared() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 4);
+
+        let norm_squared = fold_coefficients(&poly, 0, |acc, c| (acc + c * c) % modulus);
+        assert_eq!(norm_squared, (3 * 3 + 4 * 4) % modulus);
+    }
+
+    #[test]
+    fn test_is_multilinear() {
+        let modulus = 13;
+        let mut multilinear = MultiVarPolynomial::new(2, modulus);
+        multilinear.add_term(vec![1, 1], 1);
+        assert!(multilinear.is_multilinear());
+
+        let mut not_multilinear = MultiVarPolynomial::new(2, modulus);
+        not_multilinear.add_term(vec![2, 0], 1);
+        assert!(!not_multilinear.is_multilinear());
+    }
+
+    #[test]
+    fn test_equals_as_value_ignores_num_vars_for_constants() {
+        let modulus = 13;
+        let mut zero_var_constant = MultiVarPolynomial::new(0, modulus);
+        zero_var_constant.add_term(vec![], 5);
+
+        let mut multi_var_constant = MultiVarPolynomial::new(3, modulus);
+        multi_var_constant.add_term(vec![0, 0, 0], 5);
+
+        assert!(zero_var_constant.equals_as_value(&multi_var_constant));
+        assert!(multi_var_constant.equals_as_value(&zero_var_constant));
+        assert_ne!(zero_var_constant, multi_var_constant);
+    }
+
+    #[test]
+    fn test_equals_as_value_rejects_a_non_constant_polynomial() {
+        let modulus = 13;
+        let constant = MultiVarPolynomial::new(0, modulus);
+        let mut non_constant = MultiVarPolynomial::new(1, modulus);
+        non_constant.add_term(vec![1], 1);
+
+        assert!(!constant.equals_as_value(&non_constant));
+    }
+
+    #[test]
+    fn test_equals_as_value_rejects_a_modulus_mismatch() {
+        let mut a = MultiVarPolynomial::new(0, 13);
+        a.add_term(vec![], 5);
+        let mut b = MultiVarPolynomial::new(2, 17);
+        b.add_term(vec![0, 0], 5);
+
+        assert!(!a.equals_as_value(&b));
+    }
+
+    #[test]
+    fn test_derivative() {
+        let modulus = 7;
+        // 3x^2 + 2x, derivative is 6x + 2
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![2], 3);
+        poly.add_term(vec![1], 2);
+
+        let derivative = poly.derivative(0);
+
+        let mut expected = MultiVarPolynomial::new(1, modulus);
+        expected.add_term(vec![1], 6);
+        expected.add_term(vec![0], 2);
+        assert_eq!(derivative, expected);
+    }
+
+    #[test]
+    fn test_degree_summary() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![2, 1, 0], 1);
+        poly.add_term(vec![0, 3, 1], 1);
+
+        let summary = poly.degree_summary();
+        assert_eq!(summary.per_var, vec![2, 3, 1]);
+        assert_eq!(summary.total, 4);
+        assert_eq!(poly.degree_sequence(), summary.per_var);
+    }
+
+    #[test]
+    fn test_active_vars_excludes_a_variable_that_never_appears() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(4, modulus);
+        poly.add_term(vec![1, 0, 0, 0], 2);
+        poly.add_term(vec![0, 1, 0, 1], 3);
+        poly.add_term(vec![0, 0, 0, 2], 5);
+
+        assert_eq!(poly.active_vars(), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_num_terms_ignores_zeroed_out_entries_unlike_terms_len() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 5);
+        poly.add_term(vec![0, 1], 3);
+        poly.add_term(vec![0, 1], modulus - 3); // zeroes out the second entry, key remains
+
+        assert_eq!(poly.terms.len(), 2);
+        assert_eq!(poly.num_terms(), 1);
+    }
+
+    #[test]
+    fn test_estimated_bytes_scales_with_stored_entries() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 0], 4);
+
+        let bytes_per_term = 3 * core::mem::size_of::<usize>() + core::mem::size_of::<i32>();
+        assert_eq!(poly.estimated_bytes(), 2 * bytes_per_term);
+    }
+
+    #[test]
+    fn test_substitute() {
+        let modulus = 13;
+        // x_1 * x_2
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 1], 1);
+
+        // x_2 -> x_1 + 1
+        let mut replacement = MultiVarPolynomial::new(2, modulus);
+        replacement.add_term(vec![1, 0], 1);
+        replacement.add_term(vec![0, 0], 1);
+
+        let result = poly.substitute(1, &replacement).unwrap();
+
+        // Expected expansion: x_1 * (x_1 + 1) = x_1^2 + x_1
+        let mut expected = MultiVarPolynomial::new(2, modulus);
+        expected.add_term(vec![2, 0], 1);
+        expected.add_term(vec![1, 0], 1);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_compose_var_substitutes_univariate_into_one_variable() {
+        let modulus = 13;
+        // x_0 + x_1
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        // t + 1, univariate
+        let mut subst = MultiVarPolynomial::new(1, modulus);
+        subst.add_term(vec![1], 1);
+        subst.add_term(vec![0], 1);
+
+        let result = poly.compose_var(0, &subst).unwrap();
+
+        // Expected: (x_0 + 1) + x_1
+        let mut expected = MultiVarPolynomial::new(2, modulus);
+        expected.add_term(vec![1, 0], 1);
+        expected.add_term(vec![0, 0], 1);
+        expected.add_term(vec![0, 1], 1);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_compose_var_rejects_non_univariate_subst() {
+        let poly = MultiVarPolynomial::new(2, 13);
+        let subst = MultiVarPolynomial::new(2, 13);
+        assert_eq!(
+            poly.compose_var(0, &subst),
+            Err(SumcheckError::Poly(PolyError::DimensionMismatch { expected: 1, found: 2 }))
+        );
+    }
+
+    #[test]
+    fn test_cached_degree_updates_on_add_term() {
+        let mut poly = MultiVarPolynomial::new(2, 13);
+        assert_eq!(poly.degree_in_var(0), 0);
+
+        poly.add_term(vec![2, 1], 1);
+        assert_eq!(poly.degree_in_var(0), 2);
+        assert_eq!(poly.degree_in_var(1), 1);
+
+        poly.add_term(vec![5, 0], 1);
+        assert_eq!(poly.degree_in_var(0), 5);
+        assert_eq!(poly.degree_in_var(1), 1);
+    }
+
+    #[test]
+    fn test_cached_degree_drops_back_down_after_a_cancelling_sub_assign() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![3], 1);
+        assert_eq!(poly.degree_in_var(0), 3);
+
+        let mut cancel = MultiVarPolynomial::new(1, modulus);
+        cancel.add_term(vec![3], 1);
+        poly -= cancel;
+
+        assert_eq!(poly.degree_sequence(), vec![0]);
+        assert_eq!(poly.degree_in_var(0), poly.degree_sequence()[0]);
+        assert_eq!(poly.expected_round_degree(0), 0);
+    }
+
+    #[test]
+    fn test_cached_degree_resets_after_multiplying_by_zero() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![2, 1], 1);
+        assert_eq!(poly.degree_sequence(), vec![2, 1]);
+
+        poly *= 0;
+
+        assert_eq!(poly.degree_sequence(), vec![0, 0]);
+        assert_eq!(poly.degree_in_var(0), 0);
+        assert_eq!(poly.degree_in_var(1), 0);
+    }
+
+    #[test]
+    fn test_add_assign_matches_add_chain() {
+        let modulus = 13;
+        let mut a = MultiVarPolynomial::new(2, modulus);
+        a.add_term(vec![1, 0], 3);
+        let mut b = MultiVarPolynomial::new(2, modulus);
+        b.add_term(vec![0, 1], 4);
+        let mut c = MultiVarPolynomial::new(2, modulus);
+        c.add_term(vec![1, 1], 5);
+
+        let mut accumulated = a.clone();
+        accumulated += b.clone();
+        accumulated += c.clone();
+
+        let chained = a + b + c;
+        assert_eq!(accumulated, chained);
+    }
+
+    #[test]
+    fn test_mul_assign_scalar() {
+        let mut poly = MultiVarPolynomial::new(2, 13);
+        poly.add_term(vec![1, 0], 3);
+        poly *= 5;
+        assert_eq!(poly.terms.get(&vec![1, 0]), Some(&2)); // (3*5) % 13 == 2
+    }
+
+    #[test]
+    fn test_add_assign_accumulation_matches_plus_chain() {
+        let modulus = 13;
+        let mut a = MultiVarPolynomial::new(2, modulus);
+        a.add_term(vec![1, 0], 3);
+        let mut b = MultiVarPolynomial::new(2, modulus);
+        b.add_term(vec![0, 1], 4);
+        let mut c = MultiVarPolynomial::new(2, modulus);
+        c.add_term(vec![1, 0], 5);
+
+        let expected = a.clone() + b.clone() + c.clone();
+
+        let mut accumulated = a.clone();
+        accumulated += b.clone();
+        accumulated += c.clone();
+        assert_eq!(accumulated, expected);
+    }
+
+    #[test]
+    fn test_sub_assign_undoes_add_assign() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 4);
+        let original = poly.clone();
+
+        let mut delta = MultiVarPolynomial::new(2, modulus);
+        delta.add_term(vec![1, 0], 7);
+        delta.add_term(vec![0, 0], 2);
+
+        poly += delta.clone();
+        poly -= delta;
+        assert_eq!(poly, original);
+    }
+
+    #[test]
+    fn test_mul_assign_poly_matches_mul_chain() {
+        let modulus = 13;
+        let mut a = MultiVarPolynomial::new(1, modulus);
+        a.add_term(vec![1], 2);
+        a.add_term(vec![0], 1);
+        let mut b = MultiVarPolynomial::new(1, modulus);
+        b.add_term(vec![1], 3);
+        b.add_term(vec![0], 5);
+
+        let expected = a.clone() * b.clone();
+
+        let mut accumulated = a;
+        accumulated *= b;
+        assert_eq!(accumulated, expected);
+    }
+
+    #[test]
+    fn test_to_modulus_reduces_coefficients_into_the_new_field() {
+        let mut poly = MultiVarPolynomial::new(2, 97);
+        poly.add_term(vec![1, 0], 10); // 10 mod 7 == 3
+        poly.add_term(vec![0, 1], 14); // 14 mod 7 == 0, dropped
+        poly.add_term(vec![0, 0], 5); // 5 mod 7 == 5
+
+        let reduced = poly.to_modulus(7).unwrap();
+        assert_eq!(reduced.modulus, 7);
+        assert_eq!(reduced.terms.get(&vec![1, 0]), Some(&3));
+        assert_eq!(reduced.terms.get(&vec![0, 1]), None);
+        assert_eq!(reduced.terms.get(&vec![0, 0]), Some(&5));
+    }
+
+    #[test]
+    fn test_to_modulus_rejects_non_prime() {
+        let poly = MultiVarPolynomial::new(2, 97);
+        assert_eq!(poly.to_modulus(10), Err(PolyError::InvalidModulus(10)));
+    }
+
+    #[test]
+    fn test_invalid_modulus_display_names_the_smallest_factor() {
+        assert_eq!(
+            PolyError::InvalidModulus(15).to_string(),
+            "15 is not prime; its smallest factor is 3"
+        );
+    }
+
+    #[test]
+    fn test_invalid_modulus_display_falls_back_for_values_without_a_factor() {
+        assert_eq!(PolyError::InvalidModulus(1).to_string(), "1 is not a positive prime modulus");
+        assert_eq!(PolyError::InvalidModulus(-5).to_string(), "-5 is not a positive prime modulus");
+    }
+
+    #[test]
+    #[should_panic(expected = "15 is not prime; its smallest factor is 3")]
+    fn test_new_panics_with_the_smallest_factor_for_a_composite_modulus() {
+        MultiVarPolynomial::new(1, 15);
+    }
+
+    #[test]
+    fn test_pow_zero_is_the_constant_one_polynomial() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+
+        let result = poly.pow(0);
+        let mut expected = MultiVarPolynomial::new(2, modulus);
+        expected.add_term(vec![0, 0], 1);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_pow_one_is_identity() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 5);
+        assert_eq!(poly.pow(1), poly);
+    }
+
+    #[test]
+    fn test_pow_matches_repeated_multiplication() {
+        let modulus = 13;
+        // (x1 + x2)^2 = x1^2 + 2 x1 x2 + x2^2
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let squared = poly.pow(2);
+        let mut expected = MultiVarPolynomial::new(2, modulus);
+        expected.add_term(vec![2, 0], 1);
+        expected.add_term(vec![1, 1], 2);
+        expected.add_term(vec![0, 2], 1);
+        assert_eq!(squared, expected);
+        assert_eq!(squared, poly.clone() * poly);
+    }
+
+    #[test]
+    fn test_univariate_with_degree_bound_accepts_coeffs_within_bound() {
+        let modulus = 13;
+        let poly = MultiVarPolynomial::univariate_with_degree_bound(&[1, 2, 3], 2, modulus).unwrap();
+        let mut expected = MultiVarPolynomial::new(1, modulus);
+        expected.add_term(vec![0], 1);
+        expected.add_term(vec![1], 2);
+        expected.add_term(vec![2], 3);
+        assert_eq!(poly, expected);
+    }
+
+    #[test]
+    fn test_univariate_with_degree_bound_rejects_degree_3_against_bound_2() {
+        let modulus = 13;
+        let result = MultiVarPolynomial::univariate_with_degree_bound(&[1, 0, 0, 5], 2, modulus);
+        assert_eq!(result, Err(PolyError::DegreeExceedsBound { bound: 2, found: 3 }));
+    }
+
+    #[test]
+    fn test_optimal_prover_matches_compute_g_j_across_rounds() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 1, 0], 2);
+        poly.add_term(vec![0, 1, 1], 3);
+        poly.add_term(vec![1, 0, 0], 5);
+
+        let mut prover = OptimalProver::from_poly(&poly);
+        let mut challenges = Vec::new();
+        for j in 0..poly.num_vars {
+            let expected = compute_g_j(&poly, j, &challenges).unwrap();
+            assert_eq!(prover.round_poly(), expected, "round {j} mismatch");
+
+            let r = 4; // arbitrary fixed challenge for reproducibility
+            prover.fold(r);
+            challenges.push(r);
+        }
+    }
+
+    #[test]
+    fn test_streaming_prover_matches_compute_g_j_across_rounds() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(4, modulus);
+        poly.add_term(vec![1, 1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 1, 0], 3);
+        poly.add_term(vec![1, 0, 0, 1], 5);
+        poly.add_term(vec![0, 0, 1, 1], 4);
+
+        let mut prover = StreamingProver::from_poly(&poly);
+        let mut challenges = Vec::new();
+        for j in 0..poly.num_vars {
+            let expected = compute_g_j(&poly, j, &challenges).unwrap();
+            assert_eq!(prover.round_poly(), expected, "round {j} mismatch");
+
+            let r = (j as i32 * 3 + 2) % modulus; // arbitrary but varied per round
+            prover.fold(r);
+            challenges.push(r);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_compute_g_j_parallel_matches_compute_g_j_across_rounds() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(4, modulus);
+        poly.add_term(vec![1, 1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 1, 0], 3);
+        poly.add_term(vec![1, 0, 0, 1], 5);
+        poly.add_term(vec![0, 0, 1, 1], 4);
+
+        let mut challenges = Vec::new();
+        for j in 0..poly.num_vars {
+            let expected = compute_g_j(&poly, j, &challenges).unwrap();
+            let actual = compute_g_j_parallel(&poly, j, &challenges).unwrap();
+            assert_eq!(actual, expected, "round {j} mismatch");
+            challenges.push((j as i32 * 3 + 2) % modulus);
+        }
+    }
+
+    #[test]
+    fn test_compute_g_j_rejects_too_many_remaining_variables() {
+        let poly = MultiVarPolynomial::new(40, 13);
+        assert_eq!(
+            compute_g_j(&poly, 0, &[]),
+            Err(PolyError::TooManyVariables { num_vars: 39 })
+        );
+    }
+
+    #[test]
+    fn test_lagrange_interpolate_reconstructs_a_polynomial_from_its_evaluations() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![3], 2);
+        poly.add_term(vec![1], 5);
+        poly.add_term(vec![0], 7);
+
+        let points: Vec<(i32, i32)> = (0..=3)
+            .map(|x| (x, poly.evaluate(&[x]).unwrap()))
+            .collect();
+        let recovered = lagrange_interpolate(&points, modulus).unwrap();
+        assert_eq!(recovered, poly);
+    }
+
+    #[test]
+    fn test_lagrange_interpolate_rejects_a_repeated_x_coordinate() {
+        let result = lagrange_interpolate(&[(2, 3), (2, 5)], 101);
+        assert_eq!(result, Err(SumcheckError::Poly(PolyError::ZeroDenominator)));
+    }
+
+    #[test]
+    fn test_compute_g_j_by_evaluation_matches_compute_g_j_for_a_polynomial_with_per_variable_degrees() {
+        // Degree 3 in x0, degree 1 in x1: round 0 needs 4 evaluation points,
+        // round 1 needs only 2.
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![3, 0], 2);
+        poly.add_term(vec![2, 1], 4);
+        poly.add_term(vec![1, 1], 3);
+        poly.add_term(vec![0, 0], 5);
+
+        let mut challenges = Vec::new();
+        for j in 0..poly.num_vars {
+            let expected = compute_g_j(&poly, j, &challenges).unwrap();
+            let by_evaluation = compute_g_j_by_evaluation(&poly, j, &challenges).unwrap();
+            assert_eq!(by_evaluation, expected, "round {j} mismatch");
+
+            let r = (j as i32 * 3 + 2) % modulus;
+            challenges.push(r);
+        }
+    }
+
+    #[test]
+    fn test_compute_g_j_by_evaluation_queries_exactly_expected_round_degree_plus_one_points() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![3, 0], 2);
+        poly.add_term(vec![0, 1], 1);
+
+        assert_eq!(poly.expected_round_degree(0), 3);
+        assert_eq!(poly.expected_round_degree(1), 1);
+
+        // A wrong, fixed count of 2 points would under-sample round 0's
+        // degree-3 polynomial and fail to reconstruct it correctly.
+        let g_0 = compute_g_j_by_evaluation(&poly, 0, &[]).unwrap();
+        assert_eq!(g_0, compute_g_j(&poly, 0, &[]).unwrap());
+    }
+
+    #[test]
+    fn test_eval_hypercube_rejects_too_many_variables() {
+        let poly = MultiVarPolynomial::new(40, 13);
+        assert_eq!(
+            eval_hypercube(&poly),
+            Err(PolyError::TooManyVariables { num_vars: 40 })
+        );
+    }
+
+    #[test]
+    fn test_batch_mod_inverse_matches_individual_mod_inverse_calls() {
+        let modulus = 97;
+        let values = [1, 2, 3, 17, 50, 96];
+        let batch = batch_mod_inverse(&values, modulus).unwrap();
+        let individual: Vec<i32> = values
+            .iter()
+            .map(|&v| mod_inverse(v, modulus).unwrap())
+            .collect();
+        assert_eq!(batch, individual);
+    }
+
+    #[test]
+    fn test_batch_mod_inverse_rejects_a_zero_value() {
+        let modulus = 97;
+        let values = [1, 2, 0, 17];
+        assert_eq!(
+            batch_mod_inverse(&values, modulus),
+            Err(SumcheckError::Poly(PolyError::ZeroDenominator))
+        );
+    }
+
+    #[test]
+    fn test_batch_mod_inverse_of_empty_slice_is_empty() {
+        assert_eq!(batch_mod_inverse(&[], 97), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_random_field_element_stays_in_range() {
+        let modulus = 97;
+        let mut rng = rand::thread_rng();
+        for _ in 0..10000 {
+            let value = random_field_element(modulus, &mut rng);
+            assert!((0..modulus).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_gf2_protocol_accepts_a_genuine_proof() {
+        let modulus = 2;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 1, 0], 1);
+        poly.add_term(vec![0, 1, 1], 1);
+        poly.add_term(vec![1, 0, 0], 1);
+
+        let result = run_protocol_silent(poly.clone(), BTreeMap::new(), BTreeMap::new()).unwrap();
+        assert!(result.accepted);
+        assert_eq!(result.claimed_sum, poly.bool_sum());
+    }
+
+    #[test]
+    fn test_gf2_random_field_element_only_ever_returns_0_or_1() {
+        let modulus = 2;
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let value = random_field_element(modulus, &mut rng);
+            assert!(value == 0 || value == 1);
+        }
+    }
+
+    #[test]
+    fn test_gf2_degree_2_term_is_not_collapsed_to_degree_1() {
+        // x^2 and x agree as functions on {0, 1}, but `degree_in_var` and
+        // round-polynomial degree checks treat `x^2` as genuinely degree 2.
+        let modulus = 2;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![2, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        assert_eq!(poly.degree_in_var(0), 2);
+        assert_eq!(poly.evaluate(&[1, 1]).unwrap(), 0);
+        assert_eq!(poly.evaluate(&[0, 1]).unwrap(), 1);
+
+        let result = run_protocol_silent(poly.clone(), BTreeMap::new(), BTreeMap::new()).unwrap();
+        assert!(result.accepted);
+    }
+
+    #[test]
+    fn test_gf2_mod_inverse_of_the_only_nonzero_element() {
+        assert_eq!(mod_inverse(1, 2), Some(1));
+        assert_eq!(mod_inverse(0, 2), None);
+    }
+
+    #[test]
+    fn test_bool_sum_vars_matches_nested_bool_sum() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 1], 5);
+        poly.add_term(vec![1, 1, 1], 3);
+
+        // Summing out variables 1 and 2 should leave a univariate
+        // polynomial in variable 0 equal to a nested sum over both boolean
+        // assignments.
+        let summed = poly.bool_sum_vars(&[1, 2]);
+        for x0 in 0..2 {
+            let mut expected = 0i32;
+            for x1 in 0..2 {
+                for x2 in 0..2 {
+                    expected = (expected + poly.evaluate(&[x0, x1, x2]).unwrap()).rem_euclid(modulus);
+                }
+            }
+            assert_eq!(summed.evaluate(&[x0]).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_partial_eval_into_matches_partial_eval_across_reuses() {
+        let modulus = 97;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 1], 5);
+        poly.add_term(vec![1, 1, 0], 3);
+
+        let mut buf = MultiVarPolynomial::new(0, modulus);
+        for (var, value) in [(0, 4), (1, 9), (2, 1), (0, 0)] {
+            poly.partial_eval_into(&[(var, value)], &mut buf);
+            let expected = poly.partial_eval(&[(var, value)]).unwrap();
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    fn test_partial_eval_tracked_reports_original_variable_indices() {
+        let modulus = 97;
+        let mut poly = MultiVarPolynomial::new(4, modulus);
+        poly.add_term(vec![1, 0, 1, 0], 2);
+        poly.add_term(vec![0, 1, 0, 1], 5);
+
+        let (reduced, mapping) = poly.partial_eval_tracked(&[(1, 3)]).unwrap();
+        assert_eq!(mapping, vec![0, 2, 3]);
+        assert_eq!(reduced, poly.partial_eval(&[(1, 3)]).unwrap());
+    }
+
+    #[test]
+    fn test_partial_eval_tracked_propagates_duplicate_variable_error() {
+        let poly = MultiVarPolynomial::new(2, 13);
+        let result = poly.partial_eval_tracked(&[(0, 1), (0, 2)]);
+        assert_eq!(result, Err(SumcheckError::Poly(PolyError::DuplicateVariableInEval(0))));
+    }
+
+    #[test]
+    fn test_partial_eval_rejects_duplicate_variable_index() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 1], 3);
+
+        let result = poly.partial_eval(&[(0, 4), (0, 5)]);
+        assert_eq!(result, Err(PolyError::DuplicateVariableInEval(0)));
+    }
+
+    #[test]
+    fn test_partial_eval_single_pass_matches_sequential_fixes() {
+        let modulus = 97;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 1], 5);
+        poly.add_term(vec![1, 1, 0], 3);
+
+        let single_pass = poly.partial_eval(&[(0, 4), (2, 9)]).unwrap();
+        let sequential = poly.partial_eval(&[(0, 4)]).unwrap().partial_eval(&[(1, 9)]).unwrap();
+        assert_eq!(single_pass, sequential);
+    }
+
+    #[test]
+    fn test_eval_univariate_matches_evaluate() {
+        let modulus = 97;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![3], 4);
+        poly.add_term(vec![1], 5);
+        poly.add_term(vec![0], 6);
+
+        for x in [0, 1, 2, 7, -3, 50] {
+            assert_eq!(poly.eval_univariate(x).unwrap(), poly.evaluate(&[x]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_eval_univariate_rejects_multivariate_polynomial() {
+        let poly = MultiVarPolynomial::new(2, 97);
+        assert_eq!(
+            poly.eval_univariate(3),
+            Err(PolyError::DimensionMismatch { expected: 1, found: 2 })
+        );
+    }
+
+    #[test]
+    fn test_estimate_proof_bytes_multilinear_formula() {
+        let mut poly = MultiVarPolynomial::new(4, 97);
+        poly.add_term(vec![1, 0, 1, 0], 3);
+        poly.add_term(vec![0, 1, 0, 1], 5);
+        let num_vars = poly.num_vars;
+        assert_eq!(estimate_proof_bytes(&poly), num_vars * 2 * (num_vars * 2 + 4));
+    }
+
+    #[test]
+    fn test_estimate_prover_field_ops_matches_formula() {
+        let poly = MultiVarPolynomial::new(5, 97);
+        let n = poly.num_vars as u64;
+        assert_eq!(estimate_prover_field_ops(&poly), n * n * (1u64 << poly.num_vars));
+    }
+
+    #[test]
+    fn test_hypercube_sum_matches_bool_sum() {
+        let mut poly = MultiVarPolynomial::new(3, 13);
+        poly.add_term(vec![1, 0, 0], 2);
+        poly.add_term(vec![0, 1, 1], 5);
+        assert_eq!(hypercube_sum(&poly).unwrap(), poly.bool_sum());
+    }
+
+    #[test]
+    fn test_hypercube_sum_rejects_too_many_vars() {
+        let poly = MultiVarPolynomial::new(26, 13);
+        assert!(hypercube_sum(&poly).is_err());
+    }
+
+    #[test]
+    fn test_inner_product_hypercube_matches_naive_accumulation() {
+        let modulus = 13;
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 0], 3);
+        f.add_term(vec![0, 1], 2);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![1, 1], 1);
+        g.add_term(vec![0, 0], 4);
+
+        let mut naive = 0i32;
+        for point in MultiVarPolynomial::hypercube_iter(2) {
+            naive = (naive + f.evaluate(&point).unwrap() * g.evaluate(&point).unwrap()).rem_euclid(modulus);
+        }
+        assert_eq!(inner_product_hypercube(&f, &g).unwrap(), naive);
+    }
+
+    #[test]
+    fn test_inner_product_hypercube_rejects_num_vars_mismatch() {
+        let f = MultiVarPolynomial::new(2, 13);
+        let g = MultiVarPolynomial::new(3, 13);
+        assert_eq!(
+            inner_product_hypercube(&f, &g),
+            Err(SumcheckError::Poly(PolyError::DimensionMismatch { expected: 2, found: 3 }))
+        );
+    }
+
+    #[test]
+    fn test_correlated_sumcheck_accepts_a_genuine_proof() {
+        let modulus = 13;
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 0], 3);
+        f.add_term(vec![0, 1], 2);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![1, 1], 1);
+        g.add_term(vec![0, 0], 4);
+
+        let (claimed, proof) = correlated_sumcheck_prove(&f, &g).unwrap();
+        assert_eq!(claimed, inner_product_hypercube(&f, &g).unwrap());
+        assert!(correlated_sumcheck_verify(&f, &g, claimed, &proof));
+    }
+
+    #[test]
+    fn test_correlated_sumcheck_rejects_a_wrong_claim() {
+        let modulus = 13;
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 0], 3);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![0, 1], 2);
+
+        let (claimed, proof) = correlated_sumcheck_prove(&f, &g).unwrap();
+        assert!(!correlated_sumcheck_verify(&f, &g, claimed + 1, &proof));
+    }
+
+    #[test]
+    fn test_correlated_sumcheck_rejects_a_tampered_round_polynomial() {
+        let modulus = 13;
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 0], 3);
+        f.add_term(vec![0, 1], 2);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![1, 1], 1);
+        g.add_term(vec![0, 0], 4);
+
+        let (claimed, mut proof) = correlated_sumcheck_prove(&f, &g).unwrap();
+        proof.round_polys[0].add_term(vec![0], 1);
+        assert!(!correlated_sumcheck_verify(&f, &g, claimed, &proof));
+    }
+
+    #[test]
+    fn test_correlated_sumcheck_prove_rejects_num_vars_mismatch() {
+        let f = MultiVarPolynomial::new(2, 13);
+        let g = MultiVarPolynomial::new(3, 13);
+        assert_eq!(
+            correlated_sumcheck_prove(&f, &g),
+            Err(SumcheckError::Poly(PolyError::DimensionMismatch { expected: 2, found: 3 }))
+        );
+    }
+
+    #[test]
+    fn test_non_interactive_proof_round_trips() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 2);
+        poly.add_term(vec![0, 1], 3);
+        poly.add_term(vec![0, 0], 1);
+
+        let proof = prove_non_interactive(&poly, "test-statement");
+        assert!(verify_non_interactive(&poly, "test-statement", &proof));
+    }
+
+    #[test]
+    fn test_non_interactive_verify_rejects_a_mismatched_domain_separator() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 2);
+        poly.add_term(vec![0, 1], 3);
+
+        let proof = prove_non_interactive(&poly, "domain-a");
+        assert!(!verify_non_interactive(&poly, "domain-b", &proof));
+    }
+
+    #[test]
+    fn test_non_interactive_verify_rejects_a_tampered_round_polynomial() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 2);
+        poly.add_term(vec![0, 1], 3);
+
+        let mut proof = prove_non_interactive(&poly, "tamper-check");
+        proof.round_polys[0].add_term(vec![0], 1);
+        assert!(!verify_non_interactive(&poly, "tamper-check", &proof));
+    }
+
+    #[test]
+    fn test_different_domain_separators_derive_different_challenges() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 2);
+        poly.add_term(vec![0, 1], 3);
+
+        let proof_a = prove_non_interactive(&poly, "domain-a");
+        let proof_b = prove_non_interactive(&poly, "domain-b");
+        assert_ne!(proof_a.challenges, proof_b.challenges);
+    }
+
+    #[test]
+    fn test_hadamard_product_multilinear_differs_from_algebraic_product() {
+        let modulus = 13;
+        // f = x0 + x1, g = x0 + 1, both multilinear over 2 variables.
+        let mut f = MultiVarPolynomial::new(2, modulus);
+        f.add_term(vec![1, 0], 1);
+        f.add_term(vec![0, 1], 1);
+        let mut g = MultiVarPolynomial::new(2, modulus);
+        g.add_term(vec![1, 0], 1);
+        g.add_term(vec![0, 0], 1);
+
+        let hadamard = hadamard_product_multilinear(&f, &g).unwrap();
+        assert!(hadamard.is_multilinear());
+
+        let algebraic = f.clone() * g.clone();
+        assert_ne!(hadamard, algebraic);
+
+        for point in MultiVarPolynomial::hypercube_iter(2) {
+            let expected = (f.evaluate(&point).unwrap() * g.evaluate(&point).unwrap()).rem_euclid(modulus);
+            assert_eq!(hadamard.evaluate(&point).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_hadamard_product_multilinear_rejects_non_multilinear_input() {
+        let modulus = 13;
+        let mut f = MultiVarPolynomial::new(1, modulus);
+        f.add_term(vec![2], 1);
+        let mut g = MultiVarPolynomial::new(1, modulus);
+        g.add_term(vec![1], 1);
+        assert!(hadamard_product_multilinear(&f, &g).is_err());
+    }
+
+    #[test]
+    fn test_hadamard_product_multilinear_rejects_num_vars_mismatch() {
+        let f = MultiVarPolynomial::new(2, 13);
+        let g = MultiVarPolynomial::new(3, 13);
+        assert_eq!(
+            hadamard_product_multilinear(&f, &g),
+            Err(SumcheckError::Poly(PolyError::DimensionMismatch { expected: 2, found: 3 }))
+        );
+    }
+
+    #[test]
+    fn test_gkr_layer_poly_matches_the_add_mul_formula_at_sample_points() {
+        let modulus = 101;
+        // V(0) = 3, V(1) = 5.
+        let v = from_hypercube_evals(&[3, 5], modulus);
+        // add(x,y,z) = 1 only at (x,y,z) = (0,1,0).
+        let add_wiring = from_hypercube_evals(&[0, 0, 1, 0, 0, 0, 0, 0], modulus);
+        // mul(x,y,z) = 1 only at (x,y,z) = (1,1,1).
+        let mul_wiring = from_hypercube_evals(&[0, 0, 0, 0, 0, 0, 0, 1], modulus);
+
+        let f = gkr_layer_poly(&v, &add_wiring, &mul_wiring, modulus).unwrap();
+
+        // add fires, mul doesn't: f = V(1) + V(0) = 5 + 3 = 8.
+        assert_eq!(f.evaluate(&[0, 1, 0]).unwrap(), 8);
+        // mul fires, add doesn't: f = V(1) * V(1) = 5 * 5 = 25.
+        assert_eq!(f.evaluate(&[1, 1, 1]).unwrap(), 25);
+        // neither fires: f = 0.
+        assert_eq!(f.evaluate(&[0, 0, 0]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_gkr_layer_poly_rejects_a_wiring_polynomial_with_the_wrong_num_vars() {
+        let modulus = 101;
+        let v = from_hypercube_evals(&[3, 5], modulus);
+        let add_wiring = MultiVarPolynomial::new(2, modulus);
+        let mul_wiring = MultiVarPolynomial::new(3, modulus);
+        assert_eq!(
+            gkr_layer_poly(&v, &add_wiring, &mul_wiring, modulus),
+            Err(SumcheckError::Poly(PolyError::DimensionMismatch { expected: 3, found: 2 }))
+        );
+    }
+
+    #[test]
+    fn test_gkr_layer_poly_rejects_a_modulus_mismatch() {
+        let v = from_hypercube_evals(&[3, 5], 101);
+        let add_wiring = MultiVarPolynomial::new(3, 13);
+        let mul_wiring = MultiVarPolynomial::new(3, 13);
+        assert_eq!(
+            gkr_layer_poly(&v, &add_wiring, &mul_wiring, 101),
+            Err(SumcheckError::Poly(PolyError::ModulusMismatch { left: 101, right: 13 }))
+        );
+    }
+
+    /// A 2-layer, width-2 (`num_vars_per_layer == 1`) circuit: layer 1 is
+    /// `[input0 + input1, input0 * input1]`, layer 2 is `[layer1[0] *
+    /// layer1[1], layer1[0] + layer1[1]]`.
+    fn sample_gkr_circuit(modulus: i32) -> LayeredCircuit {
+        LayeredCircuit {
+            num_vars_per_layer: 1,
+            modulus,
+            gates: vec![
+                vec![GateOp::Add(0, 1), GateOp::Mul(0, 1)],
+                vec![GateOp::Mul(0, 1), GateOp::Add(0, 1)],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_layered_circuit_evaluate_matches_hand_computed_layers() {
+        let modulus = 101;
+        let circuit = sample_gkr_circuit(modulus);
+        let layers = circuit.evaluate(&[3, 5]).unwrap();
+        assert_eq!(layers[0], vec![3, 5]);
+        assert_eq!(layers[1], vec![8, 15]);
+        assert_eq!(layers[2], vec![19, 23]);
+    }
+
+    #[test]
+    fn test_gkr_prove_then_verify_accepts_an_honest_circuit_evaluation() {
+        let modulus = 101;
+        let circuit = sample_gkr_circuit(modulus);
+        let inputs = vec![3, 5];
+        let claimed_output = circuit.evaluate(&inputs).unwrap().pop().unwrap();
+
+        let proof = gkr_prove(&circuit, &inputs, &claimed_output, "gkr-test").unwrap();
+
+        let input_poly = from_hypercube_evals(&inputs, modulus);
+        assert_eq!(
+            gkr_verify(&circuit, &input_poly, &claimed_output, &proof, "gkr-test"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_gkr_verify_rejects_a_proof_checked_against_a_forged_claimed_output() {
+        // The exploit this closes: the verifier must not accept an honest
+        // proof of one output against a different, forged output.
+        let modulus = 101;
+        let circuit = sample_gkr_circuit(modulus);
+        let inputs = vec![3, 5];
+        let claimed_output = circuit.evaluate(&inputs).unwrap().pop().unwrap();
+        assert_eq!(claimed_output, vec![19, 23]);
+
+        let proof = gkr_prove(&circuit, &inputs, &claimed_output, "gkr-test").unwrap();
+
+        let input_poly = from_hypercube_evals(&inputs, modulus);
+        let forged_output = vec![20, 3];
+        assert_eq!(
+            gkr_verify(&circuit, &input_poly, &forged_output, &proof, "gkr-test"),
+            Err(SumcheckError::FinalCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_gkr_prove_rejects_a_claimed_output_that_does_not_match_the_circuit() {
+        let modulus = 101;
+        let circuit = sample_gkr_circuit(modulus);
+        let inputs = vec![3, 5];
+        assert_eq!(
+            gkr_prove(&circuit, &inputs, &[0, 0], "gkr-test"),
+            Err(SumcheckError::FinalCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_gkr_verify_rejects_a_tampered_layer_claim() {
+        let modulus = 101;
+        let circuit = sample_gkr_circuit(modulus);
+        let inputs = vec![3, 5];
+        let claimed_output = circuit.evaluate(&inputs).unwrap().pop().unwrap();
+
+        let mut proof = gkr_prove(&circuit, &inputs, &claimed_output, "gkr-test").unwrap();
+        proof.layer_claims[0].0 = (proof.layer_claims[0].0 + 1).rem_euclid(modulus);
+
+        let input_poly = from_hypercube_evals(&inputs, modulus);
+        assert_eq!(
+            gkr_verify(&circuit, &input_poly, &claimed_output, &proof, "gkr-test"),
+            Err(SumcheckError::FinalCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_gkr_verify_rejects_a_mismatched_input_polynomial() {
+        let modulus = 101;
+        let circuit = sample_gkr_circuit(modulus);
+        let inputs = vec![3, 5];
+        let claimed_output = circuit.evaluate(&inputs).unwrap().pop().unwrap();
+
+        let proof = gkr_prove(&circuit, &inputs, &claimed_output, "gkr-test").unwrap();
+
+        // The verifier is given the wrong input layer.
+        let wrong_input_poly = from_hypercube_evals(&[4, 6], modulus);
+        assert_eq!(
+            gkr_verify(&circuit, &wrong_input_poly, &claimed_output, &proof, "gkr-test"),
+            Err(SumcheckError::FinalCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_fold_first_var_at_zero_matches_partial_eval() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 3);
+        poly.add_term(vec![0, 1, 0], 2);
+        poly.add_term(vec![1, 0, 1], 5);
+        poly.add_term(vec![0, 0, 0], 7);
+
+        let folded = fold_first_var(&poly, 0).unwrap();
+        let expected = poly.partial_eval(&[(0, 0)]).unwrap();
+        assert_eq!(folded, expected);
+    }
+
+    #[test]
+    fn test_fold_first_var_at_one_matches_partial_eval() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 3);
+        poly.add_term(vec![0, 1, 0], 2);
+        poly.add_term(vec![1, 0, 1], 5);
+        poly.add_term(vec![0, 0, 0], 7);
+
+        let folded = fold_first_var(&poly, 1).unwrap();
+        let expected = poly.partial_eval(&[(0, 1)]).unwrap();
+        assert_eq!(folded, expected);
+    }
+
+    #[test]
+    fn test_fold_first_var_at_an_interior_challenge_matches_partial_eval() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 3);
+        poly.add_term(vec![0, 1], 2);
+        poly.add_term(vec![1, 1], 4);
+
+        let folded = fold_first_var(&poly, 17).unwrap();
+        let expected = poly.partial_eval(&[(0, 17)]).unwrap();
+        assert_eq!(folded, expected);
+    }
+
+    #[test]
+    fn test_fold_first_var_rejects_non_linear_first_variable() {
+        let mut poly = MultiVarPolynomial::new(1, 13);
+        poly.add_term(vec![2], 1);
+        assert!(fold_first_var(&poly, 5).is_err());
+    }
+
+    #[test]
+    fn test_expected_round_degree_matches_actual_round_polynomial_degree() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![3, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let g_0 = compute_g_j(&poly, 0, &[]).unwrap();
+        assert_eq!(poly.expected_round_degree(0), 3);
+        assert_eq!(g_0.degree_in_var(0), poly.expected_round_degree(0));
+    }
+
+    #[test]
+    fn test_cheating_prover_passes_degree_but_fails_consistency() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(4, modulus);
+        poly.add_term(vec![1, 0, 0, 0], 1);
+        poly.add_term(vec![0, 1, 0, 0], 1);
+        poly.add_term(vec![0, 0, 1, 0], 1);
+        poly.add_term(vec![0, 0, 0, 1], 1);
+
+        let mut verifier_overrides = BTreeMap::new();
+        verifier_overrides.insert(0, 2);
+        verifier_overrides.insert(1, 3);
+        verifier_overrides.insert(2, 5);
+        verifier_overrides.insert(3, 7);
+
+        // Corrupt only the constant term of the honest g_2: the degree bound
+        // still holds, but g_2(0) + g_2(1) no longer matches g_1(r_1).
+        let honest_g2 = compute_g_j(&poly, 2, &[2, 3]).unwrap();
+        let mut cheating_g2 = honest_g2.clone();
+        cheating_g2.add_term(vec![0], 1);
+
+        let mut prover_overrides = BTreeMap::new();
+        prover_overrides.insert(2, cheating_g2);
+
+        let result = run_protocol(poly, prover_overrides, verifier_overrides);
+        assert_eq!(result, Err(SumcheckError::ConsistencyCheckFailed { round: 2 }));
+    }
+
+    #[test]
+    fn test_cheating_prover_passes_consistency_but_fails_final_check() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(4, modulus);
+        poly.add_term(vec![1, 0, 0, 0], 1);
+        poly.add_term(vec![0, 1, 0, 0], 1);
+        poly.add_term(vec![0, 0, 1, 0], 1);
+        poly.add_term(vec![0, 0, 0, 1], 1);
+        poly.add_term(vec![0, 0, 0, 2], 1); // raise degree_in_var(3) to 2.
+
+        let mut verifier_overrides = BTreeMap::new();
+        verifier_overrides.insert(0, 2);
+        verifier_overrides.insert(1, 3);
+        verifier_overrides.insert(2, 5);
+        verifier_overrides.insert(3, 7);
+
+        // Add k*(x^2 - x), which vanishes at x=0 and x=1, so g_3(0)+g_3(1) is
+        // unchanged (consistency still holds) but g_3(r_3) is now wrong.
+        let honest_g3 = compute_g_j(&poly, 3, &[2, 3, 5]).unwrap();
+        let mut cheating_g3 = honest_g3.clone();
+        cheating_g3.add_term(vec![2], 1);
+        cheating_g3.add_term(vec![1], modulus - 1);
+
+        let mut prover_overrides = BTreeMap::new();
+        prover_overrides.insert(3, cheating_g3);
+
+        let result = run_protocol(poly, prover_overrides, verifier_overrides);
+        assert_eq!(result, Err(SumcheckError::FinalCheckFailed));
+    }
+
+    #[test]
+    fn test_hypercube_iter_yields_all_distinct_points() {
+        let num_vars = 4;
+        let points: Vec<Vec<i32>> = MultiVarPolynomial::hypercube_iter(num_vars).collect();
+        assert_eq!(points.len(), 1 << num_vars);
+
+        let unique: std::collections::HashSet<Vec<i32>> = points.iter().cloned().collect();
+        assert_eq!(unique.len(), points.len());
+
+        for point in &points {
+            assert_eq!(point.len(), num_vars);
+            assert!(point.iter().all(|&b| b == 0 || b == 1));
+        }
+    }
+
+    #[test]
+    fn test_mle_from_fn_builds_the_parity_mle_over_three_bits() {
+        let modulus = 13;
+        let parity = MultiVarPolynomial::mle_from_fn(3, modulus, |bits| {
+            bits.iter().fold(0i32, |acc, &b| acc ^ b as i32)
+        });
+
+        for bits in [
+            [0, 0, 0],
+            [1, 0, 0],
+            [0, 1, 0],
+            [0, 0, 1],
+            [1, 1, 0],
+            [1, 0, 1],
+            [0, 1, 1],
+            [1, 1, 1],
+        ] {
+            let expected = bits.iter().fold(0i32, |acc, &b| acc ^ b);
+            assert_eq!(parity.evaluate(&bits).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_from_truth_table_agrees_with_the_table_on_every_corner() {
+        let modulus = 13;
+        let table = "01101001";
+        let poly = MultiVarPolynomial::from_truth_table(table, modulus).unwrap();
+
+        for (mask, expected_bit) in table.chars().enumerate() {
+            let bits: Vec<i32> = (0..3).map(|i| ((mask >> i) & 1) as i32).collect();
+            let expected = if expected_bit == '1' { 1 } else { 0 };
+            assert_eq!(poly.evaluate(&bits).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_from_truth_table_rejects_a_non_power_of_two_length() {
+        assert!(matches!(
+            MultiVarPolynomial::from_truth_table("010", 13),
+            Err(PolyError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_truth_table_rejects_a_non_binary_character() {
+        assert!(matches!(
+            MultiVarPolynomial::from_truth_table("0110x001", 13),
+            Err(PolyError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_num_vars_embeds_into_larger_space() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 1], 3);
+
+        let embedded = poly.with_num_vars(4, &[1, 3]).unwrap();
+        assert_eq!(embedded.num_vars, 4);
+        assert_eq!(embedded.terms.get(&vec![0, 1, 0, 1]), Some(&3));
+        assert_eq!(embedded.terms.len(), 1);
+    }
+
+    #[test]
+    fn test_many_from_reader_parses_several_records() {
+        let input = "\
+2 13
+2
+1 0 3
+0 1 4
+
+1 97
+1
+2 5
+
+3 7
+1
+1 1 1 2
+";
+        let polys = MultiVarPolynomial::many_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(polys.len(), 3);
+
+        assert_eq!(polys[0].num_vars, 2);
+        assert_eq!(polys[0].modulus, 13);
+        assert_eq!(polys[0].terms.get(&vec![1, 0]), Some(&3));
+        assert_eq!(polys[0].terms.get(&vec![0, 1]), Some(&4));
+
+        assert_eq!(polys[1].num_vars, 1);
+        assert_eq!(polys[1].modulus, 97);
+        assert_eq!(polys[1].terms.get(&vec![2]), Some(&5));
+
+        assert_eq!(polys[2].num_vars, 3);
+        assert_eq!(polys[2].modulus, 7);
+        assert_eq!(polys[2].terms.get(&vec![1, 1, 1]), Some(&2));
+    }
+
+    #[test]
+    fn test_many_from_reader_reports_malformed_input() {
+        let input = "not a valid header\n";
+        let result = MultiVarPolynomial::many_from_reader(input.as_bytes());
+        assert!(matches!(result, Err(PolyError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_reed_solomon_encode() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![1], 1);
+        poly.add_term(vec![0], 2); // x + 2
+
+        let domain = vec![0, 1, 2, 3];
+        let codeword = reed_solomon_encode(&poly, &domain).unwrap();
+        assert_eq!(codeword, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_reed_solomon_encode_rejects_multivariate() {
+        let poly = MultiVarPolynomial::new(2, 13);
+        assert!(reed_solomon_encode(&poly, &[0, 1]).is_err());
+    }
+
+    #[test]
+    fn test_eval_over_subgroup_matches_naive_evaluation() {
+        let modulus = 17; // 17 - 1 = 16 = 2^4, so it supports an order-8 subgroup.
+        let primitive = primitive_root(modulus).unwrap();
+        let generator = modular_pow(primitive, (modulus - 1) as u32 / 8, modulus);
+
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![3], 1);
+        poly.add_term(vec![1], 2);
+        poly.add_term(vec![0], 5); // x^3 + 2x + 5
+
+        let via_ntt = eval_over_subgroup(&poly, generator, 8, modulus).unwrap();
+        let naive: Vec<i32> = (0..8u32)
+            .map(|i| poly.eval_univariate(modular_pow(generator, i, modulus)).unwrap())
+            .collect();
+        assert_eq!(via_ntt, naive);
+    }
+
+    #[test]
+    fn test_eval_over_subgroup_falls_back_to_naive_for_a_non_power_of_two_size() {
+        let modulus = 13;
+        let generator = 1; // 1^n == 1 for any subgroup size.
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![1], 1);
+        poly.add_term(vec![0], 2); // x + 2
+
+        let evals = eval_over_subgroup(&poly, generator, 3, modulus).unwrap();
+        assert_eq!(evals, vec![3, 3, 3]);
+    }
+
+    #[test]
+    fn test_eval_over_subgroup_rejects_a_non_generator() {
+        let modulus = 13;
+        let poly = MultiVarPolynomial::new(1, modulus);
+        assert!(eval_over_subgroup(&poly, 2, 4, modulus).is_err());
+    }
+
+    #[test]
+    fn test_eval_over_subgroup_rejects_multivariate() {
+        let poly = MultiVarPolynomial::new(2, 13);
+        assert!(eval_over_subgroup(&poly, 1, 4, 13).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_from_subgroup_evals_round_trips_eval_over_subgroup() {
+        let modulus = 17; // 17 - 1 = 16 = 2^4, so it supports an order-8 subgroup.
+        let primitive = primitive_root(modulus).unwrap();
+        let generator = modular_pow(primitive, (modulus - 1) as u32 / 8, modulus);
+
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![3], 1);
+        poly.add_term(vec![1], 2);
+        poly.add_term(vec![0], 5); // x^3 + 2x + 5
+
+        let evals = eval_over_subgroup(&poly, generator, 8, modulus).unwrap();
+        let recovered = interpolate_from_subgroup_evals(&evals, generator, modulus).unwrap();
+        assert_eq!(recovered, poly);
+    }
+
+    #[test]
+    fn test_interpolate_from_subgroup_evals_rejects_a_non_power_of_two_length() {
+        assert!(interpolate_from_subgroup_evals(&[1, 2, 3], 1, 13).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_from_subgroup_evals_rejects_a_non_generator() {
+        assert!(interpolate_from_subgroup_evals(&[1, 2, 3, 4], 2, 13).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_reduces_out_of_range_inputs() {
+        let modulus = 7;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![2], 3);
+        poly.add_term(vec![0], 1);
+
+        assert_eq!(
+            poly.evaluate(&[-1]).unwrap(),
+            poly.evaluate(&[modulus - 1]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eval_fraction_matches_eval_at_the_precomputed_field_element() {
+        let modulus = 7;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![2], 3);
+        poly.add_term(vec![1], 1);
+        poly.add_term(vec![0], 5);
+
+        // 1/2 mod 7 is 4, since 2 * 4 = 8 = 1 (mod 7).
+        assert_eq!(poly.eval_fraction(&[(1, 2)]).unwrap(), poly.evaluate(&[4]).unwrap());
+    }
+
+    #[test]
+    fn test_eval_fraction_rejects_a_zero_denominator() {
+        let poly = MultiVarPolynomial::new(1, 7);
+        assert_eq!(poly.eval_fraction(&[(1, 0)]), Err(PolyError::ZeroDenominator));
+    }
+
+    #[test]
+    fn test_eq_polynomial_is_indicator_on_boolean_points() {
+        let modulus = 13;
+        let r = vec![1, 0, 1];
+        let eq = eq_polynomial(&r, modulus);
+
+        assert_eq!(eq.evaluate(&r).unwrap(), 1);
+        for point in MultiVarPolynomial::hypercube_iter(r.len()) {
+            if point != r {
+                assert_eq!(eq.evaluate(&point).unwrap(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_protocol_completeness_across_primes() {
+        for &modulus in &[5, 97, 8009, 65521] {
+            let mut poly = MultiVarPolynomial::new(3, modulus);
+            poly.add_term(vec![1, 1, 0], 2);
+            poly.add_term(vec![0, 1, 1], 3);
+            poly.add_term(vec![1, 0, 1], modulus - 1);
+            poly.add_term(vec![0, 0, 0], 4);
+
+            let result = run_protocol(poly, BTreeMap::new(), BTreeMap::new());
+            assert_eq!(result, Ok(true), "protocol rejected an honest proof for modulus {modulus}");
+        }
+    }
+
+    #[test]
+    fn test_run_protocol_accepts_the_zero_polynomial() {
+        let modulus = 13;
+        let poly = MultiVarPolynomial::new(3, modulus);
+
+        assert_eq!(poly.bool_sum(), 0);
+        let result = run_protocol_silent(poly, BTreeMap::new(), BTreeMap::new()).unwrap();
+        assert!(result.accepted);
+        assert_eq!(result.claimed_sum, 0);
+        for stat in &result.round_stats {
+            assert!(stat.degree_ok);
+            assert!(stat.consistency_ok);
+        }
+    }
+
+    #[test]
+    fn test_prove_hypercube_sum_produces_a_verifiable_proof() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+
+        let proof = prove_hypercube_sum(&evals, modulus);
+        assert_eq!(proof.num_vars, 3);
+        assert_eq!(proof.claimed_sum, evals.iter().sum::<i32>().rem_euclid(modulus));
+        assert_eq!(proof.round_polys.len(), proof.num_vars);
+        assert_eq!(proof.challenges.len(), proof.num_vars);
+
+        // Independently rebuild the same MLE and replay the proof's exact
+        // round polynomials and challenges through the verifier, to confirm
+        // the proof is self-consistent rather than just trusting the struct
+        // the honest prover happened to produce.
+        let poly = from_hypercube_evals(&evals, modulus);
+        let prover_overrides: BTreeMap<usize, MultiVarPolynomial> =
+            proof.round_polys.iter().cloned().enumerate().collect();
+        let verifier_overrides: BTreeMap<usize, i32> =
+            proof.challenges.iter().copied().enumerate().collect();
+        let result = run_protocol_silent(poly, prover_overrides, verifier_overrides).unwrap();
+        assert!(result.accepted);
+        assert_eq!(result.claimed_sum, proof.claimed_sum);
+    }
+
+    #[test]
+    fn test_self_check_accepts_a_correctly_generated_proof() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+        assert_eq!(proof.self_check(&poly), Ok(()));
+    }
+
+    #[test]
+    fn test_self_check_fails_at_the_corrupted_round() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let mut proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        // Corrupt round 1's polynomial so it no longer satisfies the
+        // consistency check against round 0's challenge.
+        proof.round_polys[1].add_term(vec![0], 1);
+        assert_eq!(proof.self_check(&poly), Err(SumcheckError::ConsistencyCheckFailed { round: 1 }));
+    }
+
+    #[test]
+    fn test_diagnose_reports_every_distinct_fault_not_just_the_first() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let mut proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        // Corrupt round 0's consistency (breaks round 0 and, as a knock-on
+        // effect, round 1's running `expected`) and round 2's degree bound,
+        // two unrelated faults that both need reporting.
+        proof.round_polys[0].add_term(vec![0], 1);
+        proof.round_polys[2].add_term(vec![5], 1);
+
+        let issues = proof.diagnose(&poly);
+        assert!(issues.iter().any(|line| line.contains("round 0") && line.contains("consistency mismatch")));
+        assert!(issues.iter().any(|line| line.contains("round 2") && line.contains("degree")));
+        assert!(issues.len() >= 2);
+    }
+
+    #[test]
+    fn test_diagnose_reports_no_issues_for_a_genuinely_valid_proof() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+        assert_eq!(proof.diagnose(&poly), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_accumulate_challenges_recovers_the_proofs_own_challenges() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        let mut rng = rand::thread_rng();
+        let challenges = accumulate_challenges(&poly, &proof, &mut rng).unwrap();
+        assert_eq!(challenges, proof.challenges);
+    }
+
+    #[test]
+    fn test_accumulate_challenges_draws_fresh_challenges_when_the_proof_has_none() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        // Build a proof the same way an interactive prover would: each
+        // round's polynomial is derived from the challenges drawn so far.
+        let mut build_rng = StdRng::seed_from_u64(99);
+        let mut challenges_so_far = Vec::new();
+        let mut round_polys = Vec::new();
+        for j in 0..poly.num_vars {
+            round_polys.push(compute_g_j(&poly, j, &challenges_so_far).unwrap());
+            challenges_so_far.push(random_field_element(modulus, &mut build_rng));
+        }
+        let proof = SumcheckProof {
+            num_vars: poly.num_vars,
+            modulus,
+            claimed_sum: poly.bool_sum(),
+            round_polys,
+            challenges: Vec::new(),
+        };
+
+        // Replaying with a freshly-seeded rng started from the same seed
+        // draws the identical challenge sequence back out.
+        let mut verify_rng = StdRng::seed_from_u64(99);
+        let challenges = accumulate_challenges(&poly, &proof, &mut verify_rng).unwrap();
+        assert_eq!(challenges, challenges_so_far);
+
+        let g_last = proof.round_polys.last().unwrap();
+        assert_eq!(final_oracle_check(&poly, g_last, &challenges), Ok(()));
+    }
+
+    #[test]
+    fn test_final_oracle_check_rejects_a_mismatched_last_evaluation() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        let mut rng = rand::thread_rng();
+        let challenges = accumulate_challenges(&poly, &proof, &mut rng).unwrap();
+
+        let g_last = proof.round_polys.last().unwrap();
+        // Shift the constant term by 1 so `wrong_last`'s evaluation can never
+        // coincidentally match `g_last`'s (they'd have to differ by a
+        // multiple of `modulus`, impossible for a shift of exactly 1).
+        let mut wrong_last = g_last.clone();
+        wrong_last.add_term(vec![0], 1);
+        assert_eq!(
+            final_oracle_check(&poly, &wrong_last, &challenges),
+            Err(SumcheckError::FinalCheckFailed)
+        );
+        assert_eq!(final_oracle_check(&poly, g_last, &challenges), Ok(()));
+    }
+
+    #[test]
+    fn test_transcript_to_ascii_table_has_a_row_per_round_with_matching_checks() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+
+        let table = transcript_to_ascii_table(&proof);
+        assert!(table.starts_with("Round"));
+        for j in 1..=proof.num_vars {
+            assert!(table.contains(&format!("{j}")));
+        }
+        assert!(!table.contains('\u{2717}'));
+        assert_eq!(table.matches('\u{2713}').count(), proof.num_vars);
+    }
+
+    #[test]
+    fn test_transcript_to_ascii_table_flags_a_corrupted_round() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let mut proof = prove_hypercube_sum(&evals, modulus);
+        proof.round_polys[1].add_term(vec![0], 1);
+
+        let table = transcript_to_ascii_table(&proof);
+        assert!(table.contains('\u{2717}'));
+    }
+
+    #[test]
+    fn test_poly_to_latex_renders_coefficients_and_exponents() {
+        let modulus = 101;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![2, 1, 0], 3);
+        poly.add_term(vec![0, 0, 1], 1);
+        poly.add_term(vec![0, 0, 0], 7);
+
+        let latex = poly_to_latex(&poly, None);
+        assert!(latex.starts_with('$'));
+        assert!(latex.ends_with('$'));
+        assert!(latex.contains("3x_0^{2}x_1"));
+        // Coefficient 1 is omitted.
+        assert!(latex.contains("x_2"));
+        assert!(!latex.contains("1x_2"));
+        assert!(latex.contains('7'));
+    }
+
+    #[test]
+    fn test_poly_to_latex_uses_custom_variable_names() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 2);
+        poly.add_term(vec![0, 1], 1);
+
+        // BTreeMap orders terms by exponent vector, so [0, 1] (the `b`
+        // term) sorts before [1, 0] (the `a` term).
+        let latex = poly_to_latex(&poly, Some(&["a", "b"]));
+        assert_eq!(latex, "$b + 2a$");
+    }
+
+    #[test]
+    fn test_poly_to_latex_renders_a_coefficient_past_the_midpoint_as_negative() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![1], modulus - 1);
+
+        // modulus - 1 == -1 (mod modulus), shorter to write as "-x_0".
+        assert_eq!(poly_to_latex(&poly, None), "$-x_0$");
+    }
+
+    #[test]
+    fn test_poly_to_latex_renders_the_zero_polynomial() {
+        let poly = MultiVarPolynomial::new(2, 13);
+        assert_eq!(poly_to_latex(&poly, None), "$0$");
+    }
+
+    #[test]
+    fn test_proof_to_latex_has_one_line_per_round() {
+        let modulus = 13;
+        let evals = vec![1, 4, 9, 16];
+        let proof = prove_hypercube_sum(&evals, modulus);
+
+        let latex = proof_to_latex(&proof);
+        assert!(latex.starts_with("\\begin{align*}\n"));
+        assert!(latex.ends_with("\\end{align*}\n"));
+        for j in 1..=proof.num_vars {
+            assert!(latex.contains(&format!("g_{{{j}}}(x) &=")));
+        }
+    }
+
+    #[test]
+    fn test_poly_to_csv_and_back_round_trips() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 1, 0], 2);
+        poly.add_term(vec![0, 1, 1], 3);
+        poly.add_term(vec![0, 0, 0], 5);
+
+        let csv = poly_to_csv(&poly);
+        assert!(csv.starts_with("coefficient,var_0,var_1,var_2\n"));
+        let round_tripped = poly_from_csv(&csv, 3, modulus).unwrap();
+        assert_eq!(round_tripped, poly);
+    }
+
+    #[test]
+    fn test_poly_to_csv_excludes_zero_coefficient_terms() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![0], 0);
+        poly.add_term(vec![1], 4);
+
+        let csv = poly_to_csv(&poly);
+        assert_eq!(csv, "coefficient,var_0\n4,1\n");
+    }
+
+    #[test]
+    fn test_poly_from_csv_rejects_a_line_with_the_wrong_number_of_fields() {
+        let csv = "coefficient,var_0,var_1\n5,1\n";
+        let result = poly_from_csv(csv, 2, 13);
+        assert!(matches!(result, Err(PolyError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_poly_from_csv_rejects_a_non_numeric_field() {
+        let csv = "coefficient,var_0\nfive,1\n";
+        let result = poly_from_csv(csv, 1, 13);
+        assert!(matches!(result, Err(PolyError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_poly_to_bytes_and_back_round_trips() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 1, 0], 2);
+        poly.add_term(vec![0, 1, 1], 3);
+        poly.add_term(vec![0, 0, 0], 5);
+
+        let bytes = poly_to_bytes(&poly);
+        assert_eq!(bytes[0], 0x01);
+        let round_tripped = poly_from_bytes(&bytes).unwrap();
+        assert_eq!(round_tripped, poly);
+    }
+
+    #[test]
+    fn test_poly_from_bytes_rejects_truncated_input() {
+        let poly = {
+            let mut p = MultiVarPolynomial::new(2, 13);
+            p.add_term(vec![1, 0], 3);
+            p
+        };
+        let bytes = poly_to_bytes(&poly);
+        let result = poly_from_bytes(&bytes[..bytes.len() - 1]);
+        assert!(matches!(result, Err(PolyError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_poly_from_bytes_rejects_an_unsupported_version() {
+        let bytes = vec![0xff, 0, 0, 0, 0];
+        assert!(matches!(poly_from_bytes(&bytes), Err(PolyError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_poly_from_bytes_rejects_a_non_prime_modulus() {
+        let mut bytes = vec![0x01];
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&10i32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        assert_eq!(poly_from_bytes(&bytes), Err(PolyError::InvalidModulus(10)));
+    }
+
+    #[test]
+    fn test_sum_matches_folded_addition_across_four_polynomials() {
+        let modulus = 13;
+        let mut polys = Vec::new();
+        for i in 0..4 {
+            let mut poly = MultiVarPolynomial::new(2, modulus);
+            poly.add_term(vec![i, 1], i as i32 + 1);
+            polys.push(poly);
+        }
+
+        let summed = MultiVarPolynomial::sum(&polys).unwrap();
+        let folded = polys
+            .into_iter()
+            .reduce(|a, b| a + b)
+            .unwrap();
+        assert_eq!(summed, folded);
+    }
+
+    #[test]
+    fn test_sum_rejects_an_empty_slice() {
+        assert_eq!(MultiVarPolynomial::sum(&[]), Err(PolyError::EmptyInput));
+    }
+
+    #[test]
+    fn test_sum_rejects_mismatched_num_vars() {
+        let a = MultiVarPolynomial::new(2, 13);
+        let b = MultiVarPolynomial::new(3, 13);
+        assert_eq!(
+            MultiVarPolynomial::sum(&[a, b]),
+            Err(PolyError::DimensionMismatch { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn test_sum_rejects_mismatched_modulus() {
+        let a = MultiVarPolynomial::new(2, 13);
+        let b = MultiVarPolynomial::new(2, 17);
+        assert_eq!(
+            MultiVarPolynomial::sum(&[a, b]),
+            Err(PolyError::ModulusMismatch { left: 13, right: 17 })
+        );
+    }
+
+    #[test]
+    fn test_random_linear_combination_matches_a_sampled_weighted_sum() {
+        let modulus = 101;
+        let mut a = MultiVarPolynomial::new(2, modulus);
+        a.add_term(vec![1, 0], 3);
+        a.add_term(vec![0, 1], 2);
+
+        let mut b = MultiVarPolynomial::new(2, modulus);
+        b.add_term(vec![1, 1], 5);
+        b.add_term(vec![0, 0], 7);
+
+        let coeffs = [4, 6];
+        let combined = MultiVarPolynomial::random_linear_combination(&[a.clone(), b.clone()], &coeffs).unwrap();
+
+        let point = [2, 3];
+        let expected = (coeffs[0] as i64 * a.evaluate(&point).unwrap() as i64
+            + coeffs[1] as i64 * b.evaluate(&point).unwrap() as i64)
+            .rem_euclid(modulus as i64) as i32;
+        assert_eq!(combined.evaluate(&point).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_random_linear_combination_rejects_a_coeffs_length_mismatch() {
+        let a = MultiVarPolynomial::new(2, 13);
+        let b = MultiVarPolynomial::new(2, 13);
+        assert_eq!(
+            MultiVarPolynomial::random_linear_combination(&[a, b], &[1]),
+            Err(PolyError::DimensionMismatch { expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn test_random_linear_combination_rejects_mismatched_num_vars() {
+        let a = MultiVarPolynomial::new(2, 13);
+        let b = MultiVarPolynomial::new(3, 13);
+        assert_eq!(
+            MultiVarPolynomial::random_linear_combination(&[a, b], &[1, 1]),
+            Err(PolyError::DimensionMismatch { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn test_streaming_verifier_accepts_a_proof_processed_one_round_at_a_time() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        let mut verifier = StreamingVerifier::new(poly);
+        assert_eq!(verifier.claimed_sum, proof.claimed_sum);
+
+        let mut r = 0;
+        for (j, g_j) in proof.round_polys.iter().enumerate() {
+            r = verifier.process_round(g_j.clone(), Some(proof.challenges[j])).unwrap();
+            assert_eq!(r, proof.challenges[j]);
+        }
+        let g_last_eval = proof.round_polys.last().unwrap().evaluate(&[r]).unwrap();
+        assert_eq!(verifier.finalize(r, g_last_eval), Ok(()));
+    }
+
+    #[test]
+    fn test_streaming_verifier_rejects_a_round_with_bad_consistency() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        let mut verifier = StreamingVerifier::new(poly);
+        let mut tampered = proof.round_polys[0].clone();
+        tampered.add_term(vec![0], 1);
+        assert_eq!(
+            verifier.process_round(tampered, Some(proof.challenges[0])),
+            Err(SumcheckError::ConsistencyCheckFailed { round: 0 })
+        );
+    }
+
+    #[test]
+    fn test_streaming_verifier_rejects_finalize_before_every_round_is_processed() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        let mut verifier = StreamingVerifier::new(poly);
+        verifier
+            .process_round(proof.round_polys[0].clone(), Some(proof.challenges[0]))
+            .unwrap();
+        assert!(verifier.finalize(proof.challenges[0], 0).is_err());
+    }
+
+    #[test]
+    fn test_streaming_verifier_rejects_a_mismatched_final_eval() {
+        let modulus = 101;
+        let evals = vec![1, 4, 9, 16, 25, 36, 49, 64];
+        let proof = prove_hypercube_sum(&evals, modulus);
+        let poly = from_hypercube_evals(&evals, modulus);
+
+        let mut verifier = StreamingVerifier::new(poly);
+        let mut r = 0;
+        for (j, g_j) in proof.round_polys.iter().enumerate() {
+            r = verifier.process_round(g_j.clone(), Some(proof.challenges[j])).unwrap();
+        }
+        assert_eq!(verifier.finalize(r, verifier.state.expected + 1), Err(SumcheckError::FinalCheckFailed));
+    }
+
+    #[test]
+    fn test_grand_product_prove_verifies_known_product() {
+        let modulus = 101;
+        let values = vec![2, 3, 4, 5];
+        let mut rng = rand::thread_rng();
+        let proof = grand_product_prove(&values, modulus, &mut rng).unwrap();
+        assert_eq!(proof.claimed_product, 120 % modulus);
+        assert!(grand_product_verify(&proof, 120, modulus));
+    }
+
+    #[test]
+    fn test_grand_product_verify_rejects_wrong_claimed_product() {
+        let modulus = 101;
+        let values = vec![2, 3, 4, 5];
+        let mut rng = rand::thread_rng();
+        let proof = grand_product_prove(&values, modulus, &mut rng).unwrap();
+        assert!(!grand_product_verify(&proof, 121, modulus));
+    }
+
+    #[test]
+    fn test_grand_product_verify_rejects_tampered_aux_poly() {
+        let modulus = 101;
+        let values = vec![2, 3, 4, 5];
+        let mut rng = rand::thread_rng();
+        let mut proof = grand_product_prove(&values, modulus, &mut rng).unwrap();
+
+        // Tamper with aux_poly without updating the embedded sumcheck proof;
+        // self_check should catch the inconsistency.
+        proof.aux_poly.add_term(vec![0, 0], 1);
+        assert!(!grand_product_verify(&proof, 120, modulus));
+    }
+
+    #[test]
+    fn test_grand_product_prove_rejects_non_power_of_two_length() {
+        let modulus = 101;
+        let values = vec![2, 3, 4];
+        let mut rng = rand::thread_rng();
+        assert!(grand_product_prove(&values, modulus, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_prove_permutation_accepts_a_genuine_permutation() {
+        let modulus = 101;
+        let f = vec![2, 3, 4, 5];
+        let g = vec![5, 2, 4, 3];
+        let mut rng = rand::thread_rng();
+        let proof = prove_permutation(&f, &g, modulus, &mut rng).unwrap();
+        assert!(verify_permutation(&proof, &f, &g, modulus));
+    }
+
+    #[test]
+    fn test_verify_permutation_rejects_a_non_permutation() {
+        let modulus = 101;
+        let f = vec![2, 3, 4, 5];
+        let g = vec![2, 3, 4, 6];
+        let mut rng = rand::thread_rng();
+        let proof = prove_permutation(&f, &g, modulus, &mut rng).unwrap();
+        assert!(!verify_permutation(&proof, &f, &g, modulus));
+    }
+
+    #[test]
+    fn test_verify_permutation_rejects_mismatched_f_against_proof() {
+        let modulus = 101;
+        let f = vec![2, 3, 4, 5];
+        let g = vec![5, 2, 4, 3];
+        let mut rng = rand::thread_rng();
+        let proof = prove_permutation(&f, &g, modulus, &mut rng).unwrap();
+
+        let other_f = vec![9, 3, 4, 5];
+        assert!(!verify_permutation(&proof, &other_f, &g, modulus));
+    }
+
+    #[test]
+    fn test_prove_permutation_rejects_length_mismatch() {
+        let modulus = 101;
+        let f = vec![2, 3, 4, 5];
+        let g = vec![2, 3, 4];
+        let mut rng = rand::thread_rng();
+        assert!(prove_permutation(&f, &g, modulus, &mut rng).is_err());
+    }
+
+    fn constant_selector_poly(num_vars: usize, modulus: i32, value: i32) -> MultiVarPolynomial {
+        let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+        poly.add_term(vec![0; num_vars], value);
+        poly
+    }
+
+    #[test]
+    fn test_plonk_addition_gate_is_satisfied_by_a_consistent_wire_assignment() {
+        let modulus = 101;
+        let num_vars = 2;
+        // a + b - c = 0, i.e. c = a + b at every boolean point.
+        let gate = PlonkGateConstraint {
+            q_l: constant_selector_poly(num_vars, modulus, 1),
+            q_r: constant_selector_poly(num_vars, modulus, 1),
+            q_o: constant_selector_poly(num_vars, modulus, modulus - 1),
+            q_m: constant_selector_poly(num_vars, modulus, 0),
+            q_c: constant_selector_poly(num_vars, modulus, 0),
+        };
+
+        let a = from_hypercube_evals(&[1, 2, 3, 4], modulus);
+        let b = from_hypercube_evals(&[5, 6, 7, 8], modulus);
+        let c = from_hypercube_evals(&[6, 8, 10, 12], modulus);
+
+        assert!(gate.is_satisfied(&a, &b, &c));
+    }
+
+    #[test]
+    fn test_plonk_multiplication_gate_rejects_an_inconsistent_wire_assignment() {
+        let modulus = 101;
+        let num_vars = 2;
+        // a * b - c = 0, i.e. c = a * b at every boolean point.
+        let gate = PlonkGateConstraint {
+            q_l: constant_selector_poly(num_vars, modulus, 0),
+            q_r: constant_selector_poly(num_vars, modulus, 0),
+            q_o: constant_selector_poly(num_vars, modulus, modulus - 1),
+            q_m: constant_selector_poly(num_vars, modulus, 1),
+            q_c: constant_selector_poly(num_vars, modulus, 0),
+        };
+
+        let a = from_hypercube_evals(&[1, 2, 3, 4], modulus);
+        let b = from_hypercube_evals(&[5, 6, 7, 8], modulus);
+        let honest_c = from_hypercube_evals(&[5, 12, 21, 32], modulus);
+        let wrong_c = from_hypercube_evals(&[5, 12, 21, 99], modulus);
+
+        assert!(gate.is_satisfied(&a, &b, &honest_c));
+        assert!(!gate.is_satisfied(&a, &b, &wrong_c));
+    }
+
+    #[test]
+    fn test_logup_prove_verifies_a_genuine_lookup() {
+        let modulus = 101;
+        let table = vec![10, 20, 30, 40];
+        let witness = vec![10, 10, 30, 20];
+        let mut rng = rand::thread_rng();
+        let proof = logup_prove(&table, &witness, modulus, &mut rng).unwrap();
+        assert!(logup_verify(&proof, &table, &witness, modulus));
+    }
+
+    #[test]
+    fn test_logup_prove_rejects_witness_value_not_in_table() {
+        let modulus = 101;
+        let table = vec![10, 20, 30, 40];
+        let witness = vec![10, 99];
+        let mut rng = rand::thread_rng();
+        assert!(logup_prove(&table, &witness, modulus, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_logup_prove_rejects_non_power_of_two_table() {
+        let modulus = 101;
+        let table = vec![10, 20, 30];
+        let witness = vec![10];
+        let mut rng = rand::thread_rng();
+        assert!(logup_prove(&table, &witness, modulus, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_logup_verify_rejects_a_witness_value_outside_the_table() {
+        let modulus = 101;
+        let table = vec![10, 20, 30, 40];
+        let witness = vec![10, 10, 30, 20];
+        let mut rng = rand::thread_rng();
+        let proof = logup_prove(&table, &witness, modulus, &mut rng).unwrap();
+
+        let forged_witness = vec![10, 10, 30, 99];
+        assert!(!logup_verify(&proof, &table, &forged_witness, modulus));
+    }
+
+    #[test]
+    fn test_commit_is_the_dot_product_with_g_vec() {
+        let ipa = InnerProductArgument {
+            g_vec: vec![1, 2, 3, 4],
+            modulus: 101,
+        };
+        let poly_coeffs = vec![5, 6, 7, 8];
+        assert_eq!(commit(&ipa, &poly_coeffs), 5 + 12 + 21 + 32);
+    }
+
+    #[test]
+    fn test_prove_inner_product_verifies_a_genuine_inner_product() {
+        let modulus = 101;
+        let ipa = InnerProductArgument {
+            g_vec: vec![2, 3, 5, 7],
+            modulus,
+        };
+        let a = vec![1, 2, 3, 4];
+        let b = vec![9, 8, 7, 6];
+        let claimed = dot_product(&a, &b, modulus);
+        let commitment = commit(&ipa, &a);
+
+        let mut rng = rand::thread_rng();
+        let proof = prove_inner_product(&ipa, &a, &b, &mut rng).unwrap();
+        assert!(verify_inner_product(&proof, commitment, claimed));
+    }
+
+    #[test]
+    fn test_verify_inner_product_rejects_a_wrong_claimed_value() {
+        let modulus = 101;
+        let ipa = InnerProductArgument {
+            g_vec: vec![2, 3, 5, 7],
+            modulus,
+        };
+        let a = vec![1, 2, 3, 4];
+        let b = vec![9, 8, 7, 6];
+        let commitment = commit(&ipa, &a);
+
+        let mut rng = rand::thread_rng();
+        let proof = prove_inner_product(&ipa, &a, &b, &mut rng).unwrap();
+        assert!(!verify_inner_product(&proof, commitment, dot_product(&a, &b, modulus) + 1));
+    }
+
+    #[test]
+    fn test_verify_inner_product_rejects_a_wrong_commitment() {
+        let modulus = 101;
+        let ipa = InnerProductArgument {
+            g_vec: vec![2, 3, 5, 7],
+            modulus,
+        };
+        let a = vec![1, 2, 3, 4];
+        let b = vec![9, 8, 7, 6];
+        let claimed = dot_product(&a, &b, modulus);
+
+        let mut rng = rand::thread_rng();
+        let proof = prove_inner_product(&ipa, &a, &b, &mut rng).unwrap();
+        assert!(!verify_inner_product(&proof, commit(&ipa, &a) + 1, claimed));
+    }
+
+    #[test]
+    fn test_prove_inner_product_rejects_non_power_of_two_length() {
+        let ipa = InnerProductArgument {
+            g_vec: vec![1, 2, 3],
+            modulus: 101,
+        };
+        let a = vec![1, 2, 3];
+        let b = vec![4, 5, 6];
+        let mut rng = rand::thread_rng();
+        assert!(prove_inner_product(&ipa, &a, &b, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_prove_inner_product_rejects_length_mismatch() {
+        let ipa = InnerProductArgument {
+            g_vec: vec![1, 2, 3, 4],
+            modulus: 101,
+        };
+        let a = vec![1, 2, 3, 4];
+        let b = vec![4, 5, 6];
+        let mut rng = rand::thread_rng();
+        assert!(prove_inner_product(&ipa, &a, &b, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_check_final_eval_accepts_the_honest_evaluation() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let point = [2, 5];
+        let claimed = poly.evaluate(&point).unwrap();
+        assert!(check_final_eval(&poly, &point, claimed));
+    }
+
+    #[test]
+    fn test_check_final_eval_rejects_a_wrong_claim() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let point = [2, 5];
+        let claimed = poly.evaluate(&point).unwrap();
+        assert!(!check_final_eval(&poly, &point, claimed + 1));
+    }
+
+    #[test]
+    fn test_soundness_experiment_empirical_rate_stays_within_the_theoretical_bound() {
+        let modulus = 97;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+        poly.add_term(vec![0, 0], 3);
+
+        let true_sum = poly.bool_sum();
+        let wrong_sum = (true_sum + 1).rem_euclid(modulus);
+
+        let mut rng = rand::thread_rng();
+        let report = soundness_experiment(&poly, wrong_sum, 2000, &mut rng);
+
+        assert_eq!(report.trials, 2000);
+        assert!(report.false_accepts <= report.trials);
+        assert!(report.empirical_rate <= report.theoretical_bound + 1e-9);
+    }
+
+    #[test]
+    fn test_soundness_experiment_reports_zero_false_accepts_for_a_correct_claim() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let true_sum = poly.bool_sum();
+        let mut rng = rand::thread_rng();
+        let report = soundness_experiment(&poly, true_sum, 500, &mut rng);
+
+        assert_eq!(report.false_accepts, 0);
+        assert_eq!(report.empirical_rate, 0.0);
+    }
+
+    #[test]
+    fn test_soundness_experiment_handles_zero_trials_without_dividing_by_zero() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![1], 1);
+
+        let mut rng = rand::thread_rng();
+        let report = soundness_experiment(&poly, poly.bool_sum() + 1, 0, &mut rng);
+
+        assert_eq!(report.trials, 0);
+        assert_eq!(report.false_accepts, 0);
+        assert_eq!(report.empirical_rate, 0.0);
+    }
+
+    #[test]
+    fn test_run_protocol_silent_reports_round_stats() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let mut verifier_overrides = BTreeMap::new();
+        verifier_overrides.insert(0, 2);
+        verifier_overrides.insert(1, 5);
+
+        let result = run_protocol_silent(poly.clone(), BTreeMap::new(), verifier_overrides).unwrap();
+        assert!(result.accepted);
+        assert!(result.final_eval_check);
+        assert_eq!(result.claimed_sum, poly.bool_sum());
+        assert_eq!(result.round_stats.len(), 2);
+        assert_eq!(result.round_stats[0].challenge, 2);
+        assert_eq!(result.round_stats[1].challenge, 5);
+        assert!(result.round_stats.iter().all(|s| s.degree_ok && s.consistency_ok));
+    }
+
+    #[test]
+    fn test_shared_sumcheck_accepts_all_genuine_claims() {
+        let modulus = 13;
+        let mut poly_a = MultiVarPolynomial::new(2, modulus);
+        poly_a.add_term(vec![1, 0], 1);
+        poly_a.add_term(vec![0, 1], 1);
+        let mut poly_b = MultiVarPolynomial::new(2, modulus);
+        poly_b.add_term(vec![1, 1], 2);
+
+        let shared_challenges = [2, 5];
+        let claimed_sums = [poly_a.bool_sum(), poly_b.bool_sum()];
+        let accepted = shared_sumcheck(&[poly_a, poly_b], &claimed_sums, &shared_challenges).unwrap();
+        assert_eq!(accepted, vec![true, true]);
+    }
+
+    #[test]
+    fn test_shared_sumcheck_rejects_only_the_wrong_claim() {
+        let modulus = 13;
+        let mut poly_a = MultiVarPolynomial::new(2, modulus);
+        poly_a.add_term(vec![1, 0], 1);
+        poly_a.add_term(vec![0, 1], 1);
+        let mut poly_b = MultiVarPolynomial::new(2, modulus);
+        poly_b.add_term(vec![1, 1], 2);
+
+        let shared_challenges = [2, 5];
+        let claimed_sums = [poly_a.bool_sum(), poly_b.bool_sum() + 1];
+        let accepted = shared_sumcheck(&[poly_a, poly_b], &claimed_sums, &shared_challenges).unwrap();
+        assert_eq!(accepted, vec![true, false]);
+    }
+
+    #[test]
+    fn test_shared_sumcheck_rejects_a_polynomial_with_the_wrong_num_vars() {
+        let modulus = 13;
+        let poly = MultiVarPolynomial::new(3, modulus);
+        let result = shared_sumcheck(&[poly], &[0], &[1, 2]);
+        assert_eq!(result, Err(SumcheckError::Poly(PolyError::DimensionMismatch { expected: 2, found: 3 })));
+    }
+
+    #[test]
+    fn test_run_protocol_rejects_out_of_range_verifier_challenge() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let mut verifier_overrides = BTreeMap::new();
+        verifier_overrides.insert(0, modulus); // out of range: must be < modulus
+
+        let result = run_protocol(poly, BTreeMap::new(), verifier_overrides);
+        assert_eq!(result, Err(SumcheckError::InvalidChallenge { round: 0, value: modulus }));
+    }
+
+    #[test]
+    fn test_run_protocol_silent_produces_no_output_by_construction() {
+        // run_protocol_silent contains no println!/eprintln! calls, unlike
+        // run_protocol, so embedding callers never see unwanted stdout.
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let result = run_protocol_silent(poly, BTreeMap::new(), BTreeMap::new()).unwrap();
+        assert!(result.accepted);
+    }
+
+    #[test]
+    fn test_run_protocol_with_callback_visits_every_round() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 0, 0], 1);
+        poly.add_term(vec![0, 1, 0], 1);
+        poly.add_term(vec![0, 0, 1], 1);
+
+        let mut seen_rounds = Vec::new();
+        let result = run_protocol_with_callback(poly, BTreeMap::new(), BTreeMap::new(), |stat| {
+            seen_rounds.push(stat.round);
+        })
+        .unwrap();
+
+        assert!(result.accepted);
+        assert_eq!(seen_rounds, vec![0, 1, 2]);
     }
 
     #[test]
@@ -355,9 +6478,9 @@ mod tests {
         poly.add_term(vec![1, 0], 1);
         poly.add_term(vec![0, 1], 1);
 
-        let mut prover_overrides = HashMap::new();
+        let mut prover_overrides = BTreeMap::new();
         prover_overrides.insert(0, MultiVarPolynomial::new(1, 7));
-        let result = run_protocol(poly, prover_overrides, HashMap::new());
+        let result = run_protocol(poly, prover_overrides, BTreeMap::new());
         assert!(result.is_err());
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
 
@@ -42,9 +42,11 @@ fn read_from_input(reader: impl BufRead) -> io::Result<MultiVarPolynomial> {
 }
 
 /// Parses verifier-supplied challenges from the format `round value` per
-/// line, validating that each value lies in `[0, modulus)`.
-fn read_verifier_overrides(reader: impl BufRead, modulus: i32) -> HashMap<usize, i32> {
-    let mut overrides = HashMap::new();
+/// line. Range-checking against `[0, modulus)` happens inside
+/// `run_protocol` itself, so it applies no matter how the protocol is
+/// invoked, not just when challenges come from this file format.
+fn read_verifier_overrides(reader: impl BufRead) -> BTreeMap<usize, i32> {
+    let mut overrides = BTreeMap::new();
     for line in reader.lines() {
         let line = line.expect("failed to read line");
         if line.trim().is_empty() {
@@ -53,9 +55,6 @@ fn read_verifier_overrides(reader: impl BufRead, modulus: i32) -> HashMap<usize,
         let mut parts = line.split_whitespace();
         let round: usize = parts.next().expect("missing round").parse().expect("invalid round");
         let value: i32 = parts.next().expect("missing value").parse().expect("invalid value");
-        if value < 0 || value >= modulus {
-            panic!("verifier override {value} at round {round} is out of range [0, {modulus})");
-        }
         overrides.insert(round, value);
     }
     overrides
@@ -73,11 +72,11 @@ fn main() {
     let verifier_overrides = match std::env::args().nth(1) {
         Some(path) => {
             let file = BufReader::new(File::open(path).expect("failed to open overrides file"));
-            read_verifier_overrides(file, polynomial.modulus)
+            read_verifier_overrides(file)
         }
-        None => HashMap::new(),
+        None => BTreeMap::new(),
     };
-    match run_protocol(polynomial, HashMap::new(), verifier_overrides) {
+    match run_protocol(polynomial, BTreeMap::new(), verifier_overrides) {
         Ok(true) => println!("Protocol accepted."),
         Ok(false) => println!("Protocol rejected."),
         Err(e) => println!("Protocol error: {e}"),
@@ -0,0 +1,196 @@
+//! Number-theoretic transform support: primitive roots of `(Z/pZ)*` and the
+//! NTT itself for fast univariate polynomial multiplication.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::{mod_inverse, modular_pow, SumcheckError};
+
+fn prime_factors(mut n: i32) -> Vec<i32> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            factors.push(d);
+            while n % d == 0 {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Checks whether `g` generates the full multiplicative group `(Z/pZ)*`,
+/// i.e. `g^((p-1)/q) != 1 (mod p)` for every prime factor `q` of `p - 1`.
+pub fn is_primitive_root(g: i32, p: i32) -> bool {
+    if g <= 0 || g >= p {
+        return false;
+    }
+    let order = p - 1;
+    prime_factors(order)
+        .into_iter()
+        .all(|q| modular_pow(g, (order / q) as u32, p) != 1)
+}
+
+/// Finds the smallest generator of `(Z/pZ)*`, testing candidates
+/// `2, 3, 4, ...` in order. The factorization of `p - 1` uses trial
+/// division, which is sufficient for the prime sizes this crate targets.
+pub fn primitive_root(p: i32) -> Option<i32> {
+    (2..p).find(|&candidate| is_primitive_root(candidate, p))
+}
+
+/// In-place Cooley-Tukey NTT/INTT butterfly over `Z/modulus Z`. `omega` must
+/// be a primitive `n`-th root of unity, where `n = coeffs.len()` is a power
+/// of two. Pass `invert = true` to compute the inverse transform (which
+/// divides the result by `n`, rather than supplying `omega^-1` directly).
+pub fn ntt(coeffs: &mut [i32], modulus: i32, omega: i32, invert: bool) {
+    let n = coeffs.len();
+    assert!(n.is_power_of_two(), "NTT length must be a power of two, got {n}");
+
+    // Bit-reversal permutation.
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            coeffs.swap(i, j);
+        }
+    }
+
+    let omega = if invert {
+        mod_inverse(omega, modulus).expect("omega must be invertible mod p")
+    } else {
+        omega
+    };
+
+    let mut len = 2;
+    while len <= n {
+        let step = modular_pow(omega, (n / len) as u32, modulus);
+        for block in coeffs.chunks_mut(len) {
+            let mut w = 1i32;
+            let half = len / 2;
+            for i in 0..half {
+                let u = block[i] as i64;
+                let v = (block[i + half] as i64 * w as i64).rem_euclid(modulus as i64);
+                block[i] = ((u + v).rem_euclid(modulus as i64)) as i32;
+                block[i + half] = ((u - v).rem_euclid(modulus as i64)) as i32;
+                w = ((w as i64 * step as i64).rem_euclid(modulus as i64)) as i32;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = mod_inverse(n as i32, modulus).expect("n must be invertible mod p");
+        for c in coeffs.iter_mut() {
+            *c = ((*c as i64 * n_inv as i64).rem_euclid(modulus as i64)) as i32;
+        }
+    }
+}
+
+/// Multiplies two univariate coefficient vectors via the NTT: pads both to
+/// the next power of two covering the product's degree, transforms,
+/// multiplies pointwise, and inverse-transforms.
+///
+/// Errors with [`SumcheckError::UnsupportedOperation`] if `modulus` isn't
+/// NTT-friendly enough to supply a primitive `n`-th root of unity for the
+/// padded transform length `n` -- i.e. if `modulus - 1` isn't divisible by
+/// `n`. `omega^((modulus - 1) / n)` would still compute *something* under
+/// plain integer division in that case, but not an actual `n`-th root of
+/// unity, so the butterfly network would silently fold incorrect products
+/// instead of the requested ones. This mirrors the same check
+/// [`crate::eval_over_subgroup`] and [`crate::interpolate_from_subgroup_evals`]
+/// make before trusting a root of unity.
+pub fn ntt_mul(a: &[i32], b: &[i32], modulus: i32) -> Result<Vec<i32>, SumcheckError> {
+    let result_len = a.len() + b.len() - 1;
+    let mut n = 1usize;
+    while n < result_len {
+        n <<= 1;
+    }
+
+    if !((modulus - 1) as usize).is_multiple_of(n) {
+        return Err(SumcheckError::UnsupportedOperation(
+            "ntt_mul requires modulus - 1 to be divisible by the padded transform length".to_string(),
+        ));
+    }
+
+    let omega = primitive_root(modulus).expect("modulus must have a primitive root");
+    let omega_n = modular_pow(omega, ((modulus - 1) as usize / n) as u32, modulus);
+
+    let mut fa = a.to_vec();
+    fa.resize(n, 0);
+    let mut fb = b.to_vec();
+    fb.resize(n, 0);
+    ntt(&mut fa, modulus, omega_n, false);
+    ntt(&mut fb, modulus, omega_n, false);
+
+    let mut fc: Vec<i32> = fa
+        .iter()
+        .zip(fb.iter())
+        .map(|(&x, &y)| ((x as i64 * y as i64).rem_euclid(modulus as i64)) as i32)
+        .collect();
+    ntt(&mut fc, modulus, omega_n, true);
+    fc.truncate(result_len);
+    Ok(fc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_root_small_primes() {
+        assert_eq!(primitive_root(7), Some(3));
+        assert_eq!(primitive_root(13), Some(2));
+    }
+
+    #[test]
+    fn test_is_primitive_root_rejects_non_generator() {
+        // 2 has order 3 mod 7 (2, 4, 1), so it does not generate (Z/7Z)*.
+        assert!(!is_primitive_root(2, 7));
+        assert!(is_primitive_root(3, 7));
+    }
+
+    #[test]
+    fn test_ntt_round_trips() {
+        let modulus = 17; // 17 - 1 = 16 = 2^4, so it supports an order-8 NTT.
+        let omega = primitive_root(modulus).unwrap();
+        let omega_8 = modular_pow(omega, (modulus - 1) as u32 / 8, modulus);
+
+        let mut coeffs = vec![1, 2, 3, 4, 0, 0, 0, 0];
+        let original = coeffs.clone();
+        ntt(&mut coeffs, modulus, omega_8, false);
+        ntt(&mut coeffs, modulus, omega_8, true);
+        assert_eq!(coeffs, original);
+    }
+
+    #[test]
+    fn test_ntt_mul_matches_schoolbook() {
+        let modulus = 17;
+        let a = vec![1, 2]; // 1 + 2x
+        let b = vec![3, 4]; // 3 + 4x
+        // (1 + 2x)(3 + 4x) = 3 + 10x + 8x^2
+        let product = ntt_mul(&a, &b, modulus).unwrap();
+        assert_eq!(product, vec![3, 10, 8]);
+    }
+
+    #[test]
+    fn test_ntt_mul_rejects_a_modulus_whose_padded_length_does_not_divide_modulus_minus_one() {
+        // modulus - 1 == 100, and the product of two length-5 inputs pads
+        // to n == 16, so 100 % 16 != 0: 101 has no primitive 16th root of
+        // unity, and the butterfly network would otherwise silently fold
+        // garbage instead of the true product.
+        let modulus = 101;
+        let a = vec![1, 2, 3, 4, 5];
+        let b = vec![1, 1, 1, 1, 1];
+        assert_eq!(
+            ntt_mul(&a, &b, modulus),
+            Err(SumcheckError::UnsupportedOperation(
+                "ntt_mul requires modulus - 1 to be divisible by the padded transform length".to_string()
+            ))
+        );
+    }
+}
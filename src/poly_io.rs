@@ -0,0 +1,182 @@
+//! Streaming binary I/O for polynomials with more terms than comfortably
+//! fit in memory at once: [`PolyWriter`] and [`PolyReader`] write/read one
+//! term at a time instead of building a single `Vec<u8>` the way
+//! [`crate::poly_to_bytes`]/[`crate::poly_from_bytes`] do. The wire format
+//! is the same: 1 byte version, 4 bytes `num_vars` (little-endian `u32`), 4
+//! bytes `modulus` (little-endian `i32`), 4 bytes `num_terms` (little-endian
+//! `u32`), then `num_terms` terms of `num_vars` little-endian `u16`
+//! exponents followed by a little-endian `i32` coefficient.
+
+use std::io::{self, Read, Write};
+
+const VERSION: u8 = 0x01;
+
+/// Writes polynomial terms to `W` one at a time, after an upfront header
+/// declaring the polynomial's shape.
+pub struct PolyWriter<W: Write> {
+    inner: W,
+    pub num_vars: usize,
+    pub modulus: i32,
+    pub terms_written: u32,
+}
+
+impl<W: Write> PolyWriter<W> {
+    /// Writes the header (version, `num_vars`, `modulus`, `expected_terms`)
+    /// and returns a writer ready to stream `expected_terms` terms via
+    /// [`PolyWriter::write_term`].
+    pub fn begin(mut writer: W, num_vars: usize, modulus: i32, expected_terms: u32) -> io::Result<Self> {
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&(num_vars as u32).to_le_bytes())?;
+        writer.write_all(&modulus.to_le_bytes())?;
+        writer.write_all(&expected_terms.to_le_bytes())?;
+        Ok(PolyWriter {
+            inner: writer,
+            num_vars,
+            modulus,
+            terms_written: 0,
+        })
+    }
+
+    /// Writes one term. `exponents` must have `num_vars` entries.
+    pub fn write_term(&mut self, exponents: &[usize], coeff: i32) -> io::Result<()> {
+        assert_eq!(
+            exponents.len(),
+            self.num_vars,
+            "exponent vector must have num_vars entries"
+        );
+        for &exp in exponents {
+            self.inner.write_all(&(exp as u16).to_le_bytes())?;
+        }
+        self.inner.write_all(&coeff.rem_euclid(self.modulus).to_le_bytes())?;
+        self.terms_written += 1;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer. Does not verify `terms_written`
+    /// matches the `expected_terms` declared in [`PolyWriter::begin`] --
+    /// that's [`PolyReader`]'s job, since it's the side that knows how many
+    /// terms it actually received.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reads polynomial terms from `R` one at a time, after parsing the header
+/// [`PolyWriter::begin`] wrote.
+pub struct PolyReader<R: Read> {
+    inner: R,
+    pub num_vars: usize,
+    pub modulus: i32,
+    pub num_terms: u32,
+    terms_read: u32,
+}
+
+fn read_exact<R: Read, const N: usize>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+impl<R: Read> PolyReader<R> {
+    /// Parses the header and returns a reader ready to stream its terms via
+    /// [`PolyReader::next_term`].
+    pub fn begin(mut reader: R) -> io::Result<Self> {
+        let version = read_exact::<_, 1>(&mut reader)?[0];
+        if version != VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported format version: {version}"),
+            ));
+        }
+        let num_vars = u32::from_le_bytes(read_exact(&mut reader)?) as usize;
+        let modulus = i32::from_le_bytes(read_exact(&mut reader)?);
+        let num_terms = u32::from_le_bytes(read_exact(&mut reader)?);
+        Ok(PolyReader {
+            inner: reader,
+            num_vars,
+            modulus,
+            num_terms,
+            terms_read: 0,
+        })
+    }
+
+    /// Reads the next term, or `None` once `num_terms` terms have all been
+    /// read.
+    pub fn next_term(&mut self) -> Option<io::Result<(Vec<usize>, i32)>> {
+        if self.terms_read >= self.num_terms {
+            return None;
+        }
+        Some(self.read_term())
+    }
+
+    fn read_term(&mut self) -> io::Result<(Vec<usize>, i32)> {
+        let mut exponents = Vec::with_capacity(self.num_vars);
+        for _ in 0..self.num_vars {
+            exponents.push(u16::from_le_bytes(read_exact(&mut self.inner)?) as usize);
+        }
+        let coeff = i32::from_le_bytes(read_exact(&mut self.inner)?);
+        self.terms_read += 1;
+        Ok((exponents, coeff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiVarPolynomial;
+
+    #[test]
+    fn test_writer_and_reader_round_trip_a_polynomial() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(3, modulus);
+        poly.add_term(vec![1, 1, 0], 2);
+        poly.add_term(vec![0, 1, 1], 3);
+        poly.add_term(vec![0, 0, 0], 5);
+
+        let terms: Vec<(Vec<usize>, i32)> = poly
+            .terms
+            .iter()
+            .filter(|(_, &coeff)| coeff != 0)
+            .map(|(exponents, &coeff)| (exponents.clone(), coeff))
+            .collect();
+
+        let mut buffer = Vec::new();
+        let mut writer = PolyWriter::begin(&mut buffer, poly.num_vars, modulus, terms.len() as u32).unwrap();
+        for (exponents, coeff) in &terms {
+            writer.write_term(exponents, *coeff).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut reader = PolyReader::begin(buffer.as_slice()).unwrap();
+        assert_eq!(reader.num_vars, poly.num_vars);
+        assert_eq!(reader.modulus, modulus);
+        assert_eq!(reader.num_terms, terms.len() as u32);
+
+        let mut read_back = MultiVarPolynomial::new(poly.num_vars, modulus);
+        while let Some(term) = reader.next_term() {
+            let (exponents, coeff) = term.unwrap();
+            read_back.add_term(exponents, coeff);
+        }
+        assert!(reader.next_term().is_none());
+        assert_eq!(read_back, poly);
+    }
+
+    #[test]
+    fn test_reader_rejects_an_unsupported_version() {
+        let bytes = [0xff, 0, 0, 0, 0];
+        let result = PolyReader::begin(&bytes[..]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reader_surfaces_an_io_error_from_a_truncated_term() {
+        let mut buffer = Vec::new();
+        let mut writer = PolyWriter::begin(&mut buffer, 1, 13, 1).unwrap();
+        writer.write_term(&[2], 7).unwrap();
+        writer.finish().unwrap();
+
+        let truncated = &buffer[..buffer.len() - 1];
+        let mut reader = PolyReader::begin(truncated).unwrap();
+        assert!(reader.next_term().unwrap().is_err());
+    }
+}
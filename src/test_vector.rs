@@ -0,0 +1,158 @@
+//! A JSON-serializable test vector format for the sumcheck protocol, so a
+//! fixed-challenge run can be recorded once and replayed against this or
+//! any other implementation, for interoperability testing.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{compute_g_j, MultiVarPolynomial, PolyError, SumcheckError};
+
+/// A single term in a recorded polynomial: an exponent vector paired with
+/// its coefficient.
+type TermVec = Vec<(Vec<usize>, i32)>;
+
+/// A recorded sumcheck run: the polynomial, the verifier's challenges, and
+/// every round polynomial the prover sent, along with the expected final
+/// outcome. Serializable so it can be written to and read from disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestVector {
+    pub num_vars: usize,
+    pub modulus: i32,
+    pub terms: TermVec,
+    pub verifier_challenges: Vec<i32>,
+    pub expected_round_polys: Vec<TermVec>,
+    pub expected_claimed_sum: i32,
+    pub expected_accept: bool,
+}
+
+fn terms_of(poly: &MultiVarPolynomial) -> TermVec {
+    poly.terms
+        .iter()
+        .filter(|(_, &coeff)| coeff != 0)
+        .map(|(exponents, &coeff)| (exponents.clone(), coeff))
+        .collect()
+}
+
+fn poly_from_terms(num_vars: usize, modulus: i32, terms: &TermVec) -> MultiVarPolynomial {
+    let mut poly = MultiVarPolynomial::new(num_vars, modulus);
+    for (exponents, coeff) in terms {
+        poly.add_term(exponents.clone(), *coeff);
+    }
+    poly
+}
+
+/// Runs the honest prover against `poly` with a fixed sequence of verifier
+/// `challenges` (one per variable), recording every round polynomial and
+/// the final accept/reject outcome into a [`TestVector`].
+pub fn generate_test_vector(
+    poly: &MultiVarPolynomial,
+    challenges: &[i32],
+) -> Result<TestVector, SumcheckError> {
+    if challenges.len() != poly.num_vars {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: poly.num_vars,
+            found: challenges.len(),
+        }));
+    }
+
+    let claimed_sum = poly.bool_sum();
+    let mut expected = claimed_sum;
+    let mut expected_round_polys = Vec::with_capacity(poly.num_vars);
+    for (j, &r_j) in challenges.iter().enumerate() {
+        let g_j = compute_g_j(poly, j, &challenges[..j])?;
+        expected_round_polys.push(terms_of(&g_j));
+        expected = g_j.evaluate(&[r_j])?;
+    }
+
+    let final_eval = poly.evaluate(challenges)?;
+    Ok(TestVector {
+        num_vars: poly.num_vars,
+        modulus: poly.modulus,
+        terms: terms_of(poly),
+        verifier_challenges: challenges.to_vec(),
+        expected_round_polys,
+        expected_claimed_sum: claimed_sum,
+        expected_accept: final_eval == expected,
+    })
+}
+
+/// Replays the verifier side of the protocol against the messages stored in
+/// `tv`, returning whether it would accept. Reconstructs `poly` from
+/// `tv.terms` rather than trusting `tv.expected_*` fields, so a tampered
+/// test vector is rejected the same way a cheating prover would be.
+pub fn run_test_vector(tv: &TestVector) -> bool {
+    if tv.verifier_challenges.len() != tv.num_vars || tv.expected_round_polys.len() != tv.num_vars {
+        return false;
+    }
+    let poly = poly_from_terms(tv.num_vars, tv.modulus, &tv.terms);
+    let claimed_sum = poly.bool_sum();
+
+    let mut expected = claimed_sum;
+    for j in 0..tv.num_vars {
+        let g_j = poly_from_terms(1, tv.modulus, &tv.expected_round_polys[j]);
+
+        if g_j.degree_in_var(0) > poly.expected_round_degree(j) {
+            return false;
+        }
+        let (g0, g1) = match (g_j.evaluate(&[0]), g_j.evaluate(&[1])) {
+            (Ok(g0), Ok(g1)) => (g0, g1),
+            _ => return false,
+        };
+        if (g0 + g1).rem_euclid(tv.modulus) != expected {
+            return false;
+        }
+
+        let r_j = tv.verifier_challenges[j];
+        expected = match g_j.evaluate(&[r_j]) {
+            Ok(value) => value,
+            Err(_) => return false,
+        };
+    }
+
+    match poly.evaluate(&tv.verifier_challenges) {
+        Ok(final_eval) => final_eval == expected,
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_then_run_test_vector_accepts() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let tv = generate_test_vector(&poly, &[3, 5]).unwrap();
+        assert!(tv.expected_accept);
+        assert!(run_test_vector(&tv));
+    }
+
+    #[test]
+    fn test_tampered_test_vector_is_rejected() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let mut tv = generate_test_vector(&poly, &[3, 5]).unwrap();
+        // Corrupt the first round polynomial's constant term.
+        tv.expected_round_polys[0].push((vec![0], 1));
+        assert!(!run_test_vector(&tv));
+    }
+
+    #[test]
+    fn test_test_vector_round_trips_through_json() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let tv = generate_test_vector(&poly, &[3, 5]).unwrap();
+        let json = serde_json::to_string(&tv).unwrap();
+        let round_tripped: TestVector = serde_json::from_str(&json).unwrap();
+        assert_eq!(tv, round_tripped);
+    }
+}
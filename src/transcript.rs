@@ -0,0 +1,135 @@
+//! A Fiat-Shamir-style transcript: absorbs scalars and round polynomials
+//! into a running digest, then squeezes challenges out of it, so the
+//! non-interactive prover and verifier derive the same challenges from the
+//! same protocol messages instead of relying on shared interactive
+//! randomness.
+//!
+//! The digest itself is FNV-1a over the absorbed bytes — deterministic and
+//! collision-resistant enough for this crate's pedagogical purposes, but
+//! not a cryptographic hash. A real deployment should swap it for one
+//! (SHA-256, BLAKE3, ...); the `Transcript` API is the part worth keeping.
+
+use alloc::vec::Vec;
+
+use crate::MultiVarPolynomial;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// A domain-separated Fiat-Shamir transcript. `new` seeds the digest with a
+/// caller-chosen domain separator plus the statement's `num_vars` and
+/// `modulus`, so two proofs about different statements (or the same
+/// statement under a different domain separator) never derive the same
+/// challenges even if their messages happen to coincide.
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    state: Vec<u8>,
+}
+
+impl Transcript {
+    /// Starts a transcript for a statement with `num_vars` variables over
+    /// `modulus`, tagged with `domain_separator` so proofs for different
+    /// statements can't be confused with each other.
+    pub fn new(domain_separator: &str, num_vars: usize, modulus: i32) -> Self {
+        let mut transcript = Transcript { state: Vec::new() };
+        transcript.absorb_bytes(domain_separator.as_bytes());
+        transcript.append_scalar(num_vars as i32);
+        transcript.append_scalar(modulus);
+        transcript
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.state.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        self.state.extend_from_slice(bytes);
+    }
+
+    /// Absorbs a single field element into the transcript.
+    pub fn append_scalar(&mut self, value: i32) {
+        self.absorb_bytes(&value.to_le_bytes());
+    }
+
+    /// Absorbs a polynomial's terms (exponent vector and coefficient, for
+    /// every term in `poly.terms`'s deterministic `BTreeMap` order) into
+    /// the transcript — used to bind a round polynomial into the
+    /// transcript before deriving the next challenge from it.
+    pub fn append_poly(&mut self, poly: &MultiVarPolynomial) {
+        self.absorb_bytes(&(poly.terms.len() as u64).to_le_bytes());
+        for (exponents, &coeff) in &poly.terms {
+            self.absorb_bytes(&(exponents.len() as u64).to_le_bytes());
+            for &exp in exponents {
+                self.absorb_bytes(&(exp as u64).to_le_bytes());
+            }
+            self.append_scalar(coeff);
+        }
+    }
+
+    /// Squeezes a challenge in `[0, modulus)` out of the transcript's
+    /// current state, then absorbs the challenge itself so the next call
+    /// (even with no further `append_*` calls in between) produces a
+    /// different one.
+    pub fn challenge_scalar(&mut self, modulus: i32) -> i32 {
+        let digest = fnv1a(&self.state);
+        let challenge = (digest % modulus as u64) as i32;
+        self.append_scalar(challenge);
+        challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_changing_only_the_domain_separator_changes_the_derived_challenge() {
+        let mut a = Transcript::new("statement-a", 3, 13);
+        let mut b = Transcript::new("statement-b", 3, 13);
+
+        let mut poly = MultiVarPolynomial::new(1, 13);
+        poly.add_term(vec![1], 5);
+        a.append_poly(&poly);
+        b.append_poly(&poly);
+
+        assert_ne!(a.challenge_scalar(13), b.challenge_scalar(13));
+    }
+
+    #[test]
+    fn test_same_inputs_produce_the_same_challenge() {
+        let mut poly = MultiVarPolynomial::new(1, 13);
+        poly.add_term(vec![1], 5);
+
+        let mut a = Transcript::new("shared", 1, 13);
+        let mut b = Transcript::new("shared", 1, 13);
+        a.append_poly(&poly);
+        b.append_poly(&poly);
+
+        assert_eq!(a.challenge_scalar(13), b.challenge_scalar(13));
+    }
+
+    #[test]
+    fn test_challenge_scalar_stays_in_range() {
+        let mut transcript = Transcript::new("range-check", 1, 97);
+        for i in 0..50 {
+            transcript.append_scalar(i);
+            let challenge = transcript.challenge_scalar(97);
+            assert!((0..97).contains(&challenge));
+        }
+    }
+
+    #[test]
+    fn test_successive_challenges_differ() {
+        let mut transcript = Transcript::new("successive", 1, 10_007);
+        let first = transcript.challenge_scalar(10_007);
+        let second = transcript.challenge_scalar(10_007);
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,110 @@
+//! Recording and replaying a run's verifier challenges to/from a plain
+//! text file, so a proof that failed in the field can be re-run with the
+//! exact challenges that produced the failure instead of a fresh random
+//! run.
+//!
+//! The format is deliberately the simplest thing that works: one decimal
+//! integer per line, in round order.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::RoundStat;
+
+/// Challenges read back from a transcript file, in round order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeSource {
+    pub challenges: Vec<i32>,
+}
+
+impl ChallengeSource {
+    /// Reads one challenge per line from `path`. Blank lines are skipped.
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut challenges = Vec::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let value = line
+                .parse::<i32>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            challenges.push(value);
+        }
+        Ok(ChallengeSource { challenges })
+    }
+
+    /// The challenges as a `round -> challenge` map, ready to pass as
+    /// [`crate::run_protocol_silent`]'s `verifier_overrides` to replay this
+    /// run exactly.
+    pub fn as_overrides(&self) -> BTreeMap<usize, i32> {
+        self.challenges.iter().copied().enumerate().collect()
+    }
+}
+
+/// Writes the challenges a run actually used -- taken from
+/// [`crate::ProtocolResult::round_stats`], which already records the
+/// per-round challenge whether it came from the RNG or from a caller
+/// override -- to `path`, one per line in round order.
+pub fn write_challenge_transcript(path: &Path, round_stats: &[RoundStat]) -> io::Result<()> {
+    let mut contents = String::new();
+    for stat in round_stats {
+        contents.push_str(&stat.challenge.to_string());
+        contents.push('\n');
+    }
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_protocol_silent, MultiVarPolynomial};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_recording_then_replaying_a_run_reproduces_the_same_transcript() {
+        let modulus = 13;
+        let mut poly = MultiVarPolynomial::new(2, modulus);
+        poly.add_term(vec![1, 0], 1);
+        poly.add_term(vec![0, 1], 1);
+
+        let original = run_protocol_silent(poly.clone(), BTreeMap::new(), BTreeMap::new()).unwrap();
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("sumcheck_transcript_test_{}.txt", std::process::id()));
+        write_challenge_transcript(&path, &original.round_stats).unwrap();
+
+        let source = ChallengeSource::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(source.challenges, vec![original.round_stats[0].challenge, original.round_stats[1].challenge]);
+
+        let replayed = run_protocol_silent(poly, BTreeMap::new(), source.as_overrides()).unwrap();
+        assert_eq!(replayed.round_stats, original.round_stats);
+        assert_eq!(replayed.accepted, original.accepted);
+    }
+
+    #[test]
+    fn test_from_file_skips_blank_lines() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("sumcheck_transcript_blank_test_{}.txt", std::process::id()));
+        std::fs::write(&path, "3\n\n7\n\n").unwrap();
+
+        let source = ChallengeSource::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(source.challenges, vec![3, 7]);
+    }
+
+    #[test]
+    fn test_from_file_rejects_a_non_numeric_line() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("sumcheck_transcript_bad_test_{}.txt", std::process::id()));
+        std::fs::write(&path, "3\nnot-a-number\n").unwrap();
+
+        let result = ChallengeSource::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+}
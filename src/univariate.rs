@@ -0,0 +1,294 @@
+//! Algebra specific to univariate polynomials: division, GCD, and
+//! square-free decomposition.
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{mod_inverse, MultiVarPolynomial, PolyError, SumcheckError};
+
+fn require_univariate(poly: &MultiVarPolynomial) -> Result<(), SumcheckError> {
+    if poly.num_vars != 1 {
+        return Err(SumcheckError::Poly(PolyError::DimensionMismatch {
+            expected: 1,
+            found: poly.num_vars,
+        }));
+    }
+    Ok(())
+}
+
+fn require_field(poly: &MultiVarPolynomial) -> Result<(), SumcheckError> {
+    if !poly.is_field() {
+        return Err(SumcheckError::NotAField);
+    }
+    Ok(())
+}
+
+fn leading_term(poly: &MultiVarPolynomial) -> Option<(usize, i32)> {
+    poly.terms
+        .iter()
+        .filter(|(_, &coeff)| coeff != 0)
+        .map(|(exp, &coeff)| (exp[0], coeff))
+        .max_by_key(|&(degree, _)| degree)
+}
+
+fn subtract_univariate(a: &MultiVarPolynomial, b: &MultiVarPolynomial) -> MultiVarPolynomial {
+    let mut result = a.clone();
+    for (exponents, &coeff) in &b.terms {
+        result.add_term(exponents.clone(), -coeff);
+    }
+    result
+}
+
+/// Formal derivative of a univariate polynomial: `d/dx [c x^e] = c*e x^(e-1)`.
+pub fn differentiate_univariate(poly: &MultiVarPolynomial) -> MultiVarPolynomial {
+    let mut result = MultiVarPolynomial::new(1, poly.modulus);
+    for (exponents, &coeff) in &poly.terms {
+        let e = exponents[0];
+        if e > 0 {
+            result.add_term(vec![e - 1], coeff * e as i32);
+        }
+    }
+    result
+}
+
+/// Divides univariate polynomial `a` by `b`, returning `(quotient, remainder)`.
+pub fn poly_div_rem(
+    a: &MultiVarPolynomial,
+    b: &MultiVarPolynomial,
+) -> Result<(MultiVarPolynomial, MultiVarPolynomial), SumcheckError> {
+    require_univariate(a)?;
+    require_univariate(b)?;
+    if a.modulus != b.modulus {
+        return Err(SumcheckError::Poly(PolyError::ModulusMismatch {
+            left: a.modulus,
+            right: b.modulus,
+        }));
+    }
+    let modulus = a.modulus;
+    let (b_degree, b_lead) = leading_term(b).ok_or(SumcheckError::DivisionByZero)?;
+    let inv_b_lead = mod_inverse(b_lead, modulus).ok_or(SumcheckError::DivisionByZero)?;
+
+    let mut remainder = a.clone();
+    let mut quotient = MultiVarPolynomial::new(1, modulus);
+    while let Some((r_degree, r_lead)) = leading_term(&remainder) {
+        if r_degree < b_degree {
+            break;
+        }
+        let factor_degree = r_degree - b_degree;
+        let factor_coeff = ((r_lead as i64 * inv_b_lead as i64).rem_euclid(modulus as i64)) as i32;
+        let mut term = MultiVarPolynomial::new(1, modulus);
+        term.add_term(vec![factor_degree], factor_coeff);
+
+        quotient.add_term(vec![factor_degree], factor_coeff);
+        remainder = subtract_univariate(&remainder, &(term * b.clone()));
+    }
+    Ok((quotient, remainder))
+}
+
+/// Computes `gcd(a, b)` of univariate polynomials via the Euclidean
+/// algorithm, using `poly_div_rem` for each reduction step. The result is
+/// normalized to be monic (leading coefficient 1), as is conventional for
+/// a GCD, which is otherwise only defined up to a unit scalar.
+pub fn poly_gcd(a: &MultiVarPolynomial, b: &MultiVarPolynomial) -> Result<MultiVarPolynomial, SumcheckError> {
+    require_field(a)?;
+    require_field(b)?;
+    let mut x = a.clone();
+    x.normalize();
+    let mut y = b.clone();
+    y.normalize();
+    while !y.terms.is_empty() {
+        let (_, remainder) = poly_div_rem(&x, &y)?;
+        x = y;
+        y = remainder;
+        y.normalize();
+    }
+    if let Some((_, lead)) = leading_term(&x) {
+        if lead != 1 {
+            let inv = mod_inverse(lead, x.modulus).expect("modulus is prime, so every non-zero element is invertible");
+            for coeff in x.terms.values_mut() {
+                *coeff = ((*coeff as i64 * inv as i64).rem_euclid(x.modulus as i64)) as i32;
+            }
+        }
+    }
+    Ok(x)
+}
+
+/// Checks whether `poly` is monic (leading coefficient 1). A non-univariate
+/// or zero polynomial is not monic.
+pub fn is_monic(poly: &MultiVarPolynomial) -> bool {
+    if require_univariate(poly).is_err() {
+        return false;
+    }
+    let mut normalized = poly.clone();
+    normalized.normalize();
+    matches!(leading_term(&normalized), Some((_, lead)) if lead == 1)
+}
+
+/// Scales a univariate polynomial by the inverse of its leading
+/// coefficient, so the result is monic — the canonical representation for
+/// a GCD, which [`poly_gcd`] is otherwise only defined up to a unit scalar.
+///
+/// Errors if `poly` isn't univariate, or is the zero polynomial (whose
+/// leading coefficient, and hence whose monic form, is undefined).
+pub fn make_monic(poly: &MultiVarPolynomial) -> Result<MultiVarPolynomial, SumcheckError> {
+    require_univariate(poly)?;
+    require_field(poly)?;
+    let mut result = poly.clone();
+    result.normalize();
+    let (_, lead) = leading_term(&result).ok_or(SumcheckError::DivisionByZero)?;
+    let inv = mod_inverse(lead, result.modulus).expect("modulus is prime, so every non-zero element is invertible");
+    for coeff in result.terms.values_mut() {
+        *coeff = ((*coeff as i64 * inv as i64).rem_euclid(result.modulus as i64)) as i32;
+    }
+    Ok(result)
+}
+
+fn is_constant(poly: &MultiVarPolynomial) -> bool {
+    let mut normalized = poly.clone();
+    normalized.normalize();
+    normalized.terms.keys().all(|exponents| exponents[0] == 0)
+}
+
+/// Square-free decomposition of a univariate polynomial via Yun's
+/// algorithm: returns `(factor, multiplicity)` pairs whose product of
+/// `factor^multiplicity` reconstructs the original polynomial.
+///
+/// Errors if the polynomial isn't univariate, or if its formal derivative
+/// is the zero polynomial (the characteristic-p edge case where Yun's
+/// algorithm does not apply).
+pub fn square_free_decomposition(
+    poly: &MultiVarPolynomial,
+) -> Result<Vec<(MultiVarPolynomial, usize)>, SumcheckError> {
+    require_univariate(poly)?;
+
+    let f_prime = differentiate_univariate(poly);
+    let mut f_prime_normalized = f_prime.clone();
+    f_prime_normalized.normalize();
+    if f_prime_normalized.terms.is_empty() {
+        return Err(SumcheckError::UnsupportedOperation(
+            "derivative vanishes identically; Yun's algorithm does not apply".to_string(),
+        ));
+    }
+
+    let a0 = poly_gcd(poly, &f_prime)?;
+    let (mut b, _) = poly_div_rem(poly, &a0)?;
+    let (c, _) = poly_div_rem(&f_prime, &a0)?;
+    let mut d = subtract_univariate(&c, &differentiate_univariate(&b));
+
+    let mut factors = Vec::new();
+    let mut i = 1usize;
+    while !is_constant(&b) {
+        let a_i = poly_gcd(&b, &d)?;
+        let mut a_i_normalized = a_i.clone();
+        a_i_normalized.normalize();
+        if !is_constant(&a_i_normalized) {
+            factors.push((a_i.clone(), i));
+        }
+
+        let (next_b, _) = poly_div_rem(&b, &a_i)?;
+        let (next_c, _) = poly_div_rem(&d, &a_i)?;
+        d = subtract_univariate(&next_c, &differentiate_univariate(&next_b));
+        b = next_b;
+        i += 1;
+    }
+    Ok(factors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poly_div_rem() {
+        let modulus = 13;
+        // (x^2 - 1) / (x - 1) = x + 1, remainder 0.
+        let mut a = MultiVarPolynomial::new(1, modulus);
+        a.add_term(vec![2], 1);
+        a.add_term(vec![0], modulus - 1);
+        let mut b = MultiVarPolynomial::new(1, modulus);
+        b.add_term(vec![1], 1);
+        b.add_term(vec![0], modulus - 1);
+
+        let (quotient, remainder) = poly_div_rem(&a, &b).unwrap();
+        let mut expected_quotient = MultiVarPolynomial::new(1, modulus);
+        expected_quotient.add_term(vec![1], 1);
+        expected_quotient.add_term(vec![0], 1);
+        assert_eq!(quotient, expected_quotient);
+        let mut zero = remainder.clone();
+        zero.normalize();
+        assert!(zero.terms.is_empty());
+    }
+
+    #[test]
+    fn test_make_monic_normalizes_leading_coefficient() {
+        let modulus = 7;
+        // 3x^2 + 6x + 9 mod 7 = 3x^2 + 6x + 2
+        let mut poly = MultiVarPolynomial::new(1, modulus);
+        poly.add_term(vec![2], 3);
+        poly.add_term(vec![1], 6);
+        poly.add_term(vec![0], 9);
+
+        assert!(!is_monic(&poly));
+        let monic = make_monic(&poly).unwrap();
+        assert!(is_monic(&monic));
+
+        let mut expected = MultiVarPolynomial::new(1, modulus);
+        expected.add_term(vec![2], 1);
+        expected.add_term(vec![1], 2);
+        expected.add_term(vec![0], 3);
+        assert_eq!(monic, expected);
+    }
+
+    #[test]
+    fn test_make_monic_rejects_zero_polynomial() {
+        let poly = MultiVarPolynomial::new(1, 7);
+        assert!(make_monic(&poly).is_err());
+        assert!(!is_monic(&poly));
+    }
+
+    #[test]
+    fn test_make_monic_rejects_a_ring_polynomial() {
+        let mut poly = MultiVarPolynomial::new_ring(1, 12);
+        poly.add_term(vec![1], 5);
+        assert_eq!(make_monic(&poly), Err(SumcheckError::NotAField));
+    }
+
+    #[test]
+    fn test_poly_gcd_rejects_a_ring_polynomial() {
+        let mut a = MultiVarPolynomial::new_ring(1, 12);
+        a.add_term(vec![1], 5);
+        let mut b = MultiVarPolynomial::new_ring(1, 12);
+        b.add_term(vec![0], 3);
+        assert_eq!(poly_gcd(&a, &b), Err(SumcheckError::NotAField));
+    }
+
+    #[test]
+    fn test_make_monic_rejects_non_univariate() {
+        let poly = MultiVarPolynomial::new(2, 7);
+        assert!(make_monic(&poly).is_err());
+        assert!(!is_monic(&poly));
+    }
+
+    #[test]
+    fn test_square_free_decomposition_reconstructs_original() {
+        let modulus = 13;
+        // f = (x - 1)^2 * (x - 2) = x^3 - 4x^2 + 5x - 2
+        let mut f = MultiVarPolynomial::new(1, modulus);
+        f.add_term(vec![3], 1);
+        f.add_term(vec![2], modulus - 4);
+        f.add_term(vec![1], 5);
+        f.add_term(vec![0], modulus - 2);
+
+        let factors = square_free_decomposition(&f).unwrap();
+
+        let mut product = MultiVarPolynomial::new(1, modulus);
+        product.add_term(vec![0], 1);
+        for (factor, multiplicity) in &factors {
+            for _ in 0..*multiplicity {
+                product *= factor.clone();
+            }
+        }
+        assert_eq!(product, f);
+    }
+}